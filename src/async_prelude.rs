@@ -0,0 +1,11 @@
+//! Traits for the async waiters. Needs the `async` feature enabled.
+//!
+//! It contains [`AsyncTimedWaiter`], and the async
+//! [`DelayNs`](embedded_hal_async::delay::DelayNs) for calling its methods,
+//! plus [`ExtU32`](fugit::ExtU32) for durations.
+//! It doesn't contain the blocking traits, so it can be imported next to
+//! [`prelude`](crate::prelude) without name collisions.
+
+pub use crate::AsyncTimedWaiter;
+pub use embedded_hal_async::delay::DelayNs as _;
+pub use fugit::ExtU32 as _;
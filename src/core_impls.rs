@@ -0,0 +1,67 @@
+use super::*;
+
+impl<I: Interval> Interval for &I {
+    #[inline(always)]
+    fn interval(&self) {
+        (*self).interval()
+    }
+}
+
+/// `None` does nothing like [`NonInterval`], `Some` calls the inner interval.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use waiter_trait::{prelude::*, StdInterval, StdWaiter};
+///
+/// let w = StdWaiter::new(
+///     Duration::from_millis(10),
+///     Some(StdInterval::new(Duration::from_millis(10))),
+/// );
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// assert!(t.timeout());
+///
+/// let w = StdWaiter::new(Duration::from_millis(10), None::<StdInterval>);
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// assert!(!t.timeout());
+/// ```
+impl<I: Interval> Interval for Option<I> {
+    #[inline]
+    fn interval(&self) {
+        if let Some(i) = self {
+            i.interval();
+        }
+    }
+}
+
+/// Calls `interval()` of each element in order, from the first to the last.
+///
+/// # Examples
+///
+/// ```
+/// use core::cell::RefCell;
+/// use waiter_trait::{FnInterval, Interval};
+///
+/// let order = RefCell::new(Vec::new());
+/// let feed0 = || order.borrow_mut().push(0);
+/// let feed1 = || order.borrow_mut().push(1);
+/// let feed2 = || order.borrow_mut().push(2);
+/// let i: [FnInterval<&dyn Fn()>; 3] = [
+///     FnInterval::new(&feed0),
+///     FnInterval::new(&feed1),
+///     FnInterval::new(&feed2),
+/// ];
+/// i.interval();
+/// assert_eq!(*order.borrow(), [0, 1, 2]);
+/// ```
+impl<I: Interval, const N: usize> Interval for [I; N] {
+    #[inline]
+    fn interval(&self) {
+        for i in self {
+            i.interval();
+        }
+    }
+}
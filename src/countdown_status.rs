@@ -0,0 +1,144 @@
+use super::*;
+use fugit::MicrosDurationU32;
+
+/// [`WaiterStatus`] returned by [`TickWaiter::start_countdown()`].
+///
+/// It counts the remaining ticks down instead of counting the elapsed ticks up,
+/// so [`remaining_ticks()`](Self::remaining_ticks) is a plain field read and
+/// each poll only compares against zero.
+///
+/// The tradeoff is that it can't be reused like the periodic
+/// [`TickWaiterStatus`]: when a poll's delta exceeds the remaining ticks, it's clamped to zero
+/// and the overshoot is lost. So it's one-shot, once the time limit expires,
+/// `timeout()` keeps returning `true` until `restart()` is called,
+/// without reading the clock or running the interval.
+pub struct TickCountdownStatus<T, I, N> {
+    tick: T,
+    remaining_tick: N,
+    timeout_tick: N,
+    frequency: u32,
+    poll_count: u32,
+    slept: bool,
+    interval: I,
+}
+
+impl<T, I, N> TickCountdownStatus<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    pub(crate) fn new(timeout_tick: N, frequency: u32, interval: I) -> Self {
+        Self {
+            tick: T::now(),
+            remaining_tick: timeout_tick,
+            timeout_tick,
+            frequency,
+            poll_count: 0,
+            slept: false,
+            interval,
+        }
+    }
+
+    /// The remaining ticks at the last sample.
+    #[inline(always)]
+    pub fn remaining_ticks(&self) -> N {
+        self.remaining_tick
+    }
+}
+
+impl<T, I, N> WaiterStatus for TickCountdownStatus<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        let expired = self.check();
+        interval_unless(expired, &self.interval, &mut self.slept)
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.poll_count = self.poll_count.saturating_add(1);
+        if self.remaining_tick == N::ZERO {
+            return true;
+        }
+        self.refresh();
+        self.remaining_tick == N::ZERO
+    }
+
+    #[inline(always)]
+    fn restart(&mut self) {
+        self.tick = T::now();
+        self.remaining_tick = self.timeout_tick;
+        self.poll_count = 0;
+        self.slept = false;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        let now = T::now();
+        let delta = N::from_u64(now.tick_since(self.tick) as u64).unwrap_or(N::MAX);
+        if delta >= self.remaining_tick {
+            self.remaining_tick = N::ZERO;
+        } else {
+            self.remaining_tick -= delta;
+        }
+        self.tick = now;
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        let elapsed_tick = self.timeout_tick.as_u64() - self.remaining_tick.as_u64();
+        Some(ticks_to_us(elapsed_tick, self.frequency))
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.remaining_tick.as_u64(), self.frequency))
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.timeout_tick.as_u64(), self.frequency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockInstant, assert_reuse_contract};
+    use fugit::ExtU32;
+
+    #[test]
+    fn same_as_count_up() {
+        let w = TickWaiter::<MockInstant, _, _>::us(
+            100.micros(),
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        let mut up = w.start_oneshot();
+        let mut down = w.start_countdown();
+        for step in [0, 30, 45, 24, 1, 0] {
+            MockInstant::advance(step);
+            assert_eq!(up.timeout(), down.timeout());
+            assert_eq!(up.elapsed(), down.elapsed());
+            assert_eq!(up.remaining(), down.remaining());
+        }
+        assert_eq!(down.remaining_ticks(), 0);
+
+        down.restart();
+        assert_eq!(down.remaining_ticks(), 100);
+        MockInstant::advance(150);
+        assert_reuse_contract(&mut down);
+    }
+}
@@ -44,11 +44,48 @@ impl Counter {
     }
 }
 
+/// Count how many `timeout()` calls on a [`Counter`] fit in `duration`, measured with `T`.
+///
+/// Divide the result by `duration` to convert between [`Counter`] retries and real time,
+/// so a [`Counter`] can approximate a real-time timeout without a clock.
+/// The clock is read once every few calls, so its own cost is mostly left out.
+///
+/// The result depends on the platform, the optimization level and the code around the loop,
+/// so calibrate in the same build that uses it.
+///
+/// - `frequency`: the frequency of `T`.
+///
+/// # Notes
+///
+/// - `duration` saturates at `u32::MAX` ticks of `T`, e.g. about 4.29 s with
+///   `std::time::Instant`, which counts nanoseconds. A longer `duration` is measured
+///   as that shorter window, so keep it below the limit.
+/// - The result saturates at `u32::MAX` calls.
+pub fn calibrate_iterations<T: TickInstant>(duration: MicrosDurationU32, frequency: u32) -> u32 {
+    const BATCH: u32 = 32;
+    let duration_tick: u32 = us_to_ticks(duration.ticks() as u64, frequency);
+    let c = Counter::new(usize::MAX);
+    let mut t = c.start();
+    let mut tick = T::now();
+    let mut elapsed_tick = 0u32;
+    while elapsed_tick < duration_tick {
+        for _ in 0..BATCH {
+            t.timeout();
+        }
+        // Accumulate, so the counter wrapping around doesn't end it early or late.
+        let now = T::now();
+        elapsed_tick = elapsed_tick.saturating_add(now.tick_since(tick));
+        tick = now;
+    }
+    t.poll_count()
+}
+
 impl Waiter for Counter {
     #[inline]
     fn start(&self) -> impl WaiterStatus {
         CounterInstance {
             count: 0,
+            poll_count: 0,
             waiter: self,
         }
     }
@@ -56,12 +93,14 @@ impl Waiter for Counter {
 
 pub struct CounterInstance<'a> {
     count: usize,
+    poll_count: u32,
     waiter: &'a Counter,
 }
 
 impl<'a> WaiterStatus for CounterInstance<'a> {
     #[inline]
     fn timeout(&mut self) -> bool {
+        self.poll_count = self.poll_count.saturating_add(1);
         if self.waiter.retry_times == usize::MAX {
             return false;
         }
@@ -77,5 +116,37 @@ impl<'a> WaiterStatus for CounterInstance<'a> {
     #[inline(always)]
     fn restart(&mut self) {
         self.count = 0;
+        self.poll_count = 0;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calibrate_iterations() {
+        use fugit::ExtU32;
+        use std::time::Instant;
+
+        let short = super::calibrate_iterations::<Instant>(1.millis(), 1_000_000_000);
+        let long = super::calibrate_iterations::<Instant>(10.millis(), 1_000_000_000);
+        assert!(short > 0);
+        assert!(long > short * 3);
+    }
+
+    #[test]
+    fn is_reusable() {
+        let c = Counter::new(1);
+        let mut t = c.start();
+        assert!(!t.is_reusable());
+        assert!(!t.timeout());
+        crate::mock::assert_reuse_contract(&mut t);
     }
 }
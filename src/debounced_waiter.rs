@@ -0,0 +1,231 @@
+use super::*;
+use core::marker::PhantomData;
+use fugit::MicrosDurationU32;
+
+/// Ignore `restart()` calls that come too soon after the previous effective one.
+///
+/// It wraps a [`Waiter`] or [`TimedWaiter`] used as an inactivity timeout.
+/// `restart()` only resets the inner status if at least `guard` has passed since the
+/// last effective restart (or since `start()`), so rapid spurious activity can't keep
+/// the timeout alive forever. `timeout()` is forwarded to the inner status unchanged.
+///
+/// The time since the last effective restart is accumulated at each poll and saturates,
+/// so like [`TickWaiterStatus`], it must be polled at least once every `u32::MAX` ticks.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, DebouncedWaiter, StdWaiter, NonInterval};
+///
+/// let w = DebouncedWaiter::<_, Instant>::new(
+///     StdWaiter::new(Duration::from_millis(50), NonInterval::new()),
+///     100.millis(),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+///
+/// let mut t = w.start();
+/// std::thread::sleep(Duration::from_millis(60));
+/// // Ignored, it's within the guard window.
+/// t.restart();
+/// assert!(t.timeout());
+/// ```
+pub struct DebouncedWaiter<W, T> {
+    waiter: W,
+    guard_tick: u32,
+    _t: PhantomData<T>,
+}
+
+impl<W, T> DebouncedWaiter<W, T>
+where
+    T: TickInstant,
+{
+    /// - `waiter`: the inner waiter.
+    /// - `guard`: the minimum time between two effective `restart()` calls.
+    ///   It's rounded up to whole ticks and saturates at `u32::MAX` ticks.
+    /// - `frequency`: the frequency of `T`.
+    pub fn new(waiter: W, guard: MicrosDurationU32, frequency: u32) -> Self {
+        Self {
+            waiter,
+            guard_tick: us_to_ticks(guard.ticks() as u64, frequency),
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<W, T> Waiter for DebouncedWaiter<W, T>
+where
+    W: Waiter,
+    T: TickInstant,
+{
+    fn start(&self) -> impl WaiterStatus {
+        DebouncedStatus {
+            status: self.waiter.start(),
+            tick: T::now(),
+            since_restart: 0,
+            guard_tick: self.guard_tick,
+        }
+    }
+}
+
+impl<W, T> TimedWaiter for DebouncedWaiter<W, T>
+where
+    W: TimedWaiter,
+    T: TickInstant,
+{
+    fn start(&self, timeout: MicrosDurationU32) -> impl WaiterStatus {
+        DebouncedStatus {
+            status: self.waiter.start(timeout),
+            tick: T::now(),
+            since_restart: 0,
+            guard_tick: self.guard_tick,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`DebouncedWaiter`].
+pub struct DebouncedStatus<S, T> {
+    status: S,
+    tick: T,
+    since_restart: u32,
+    guard_tick: u32,
+}
+
+impl<S, T: TickInstant> DebouncedStatus<S, T> {
+    #[inline]
+    fn sample(&mut self) {
+        let now = T::now();
+        self.since_restart = self.since_restart.saturating_add(now.tick_since(self.tick));
+        self.tick = now;
+    }
+}
+
+impl<S, T> WaiterStatus for DebouncedStatus<S, T>
+where
+    S: WaiterStatus,
+    T: TickInstant,
+{
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        self.sample();
+        self.status.timeout()
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.sample();
+        self.status.check()
+    }
+
+    /// Ignored if it's called within the guard window.
+    #[inline]
+    fn restart(&mut self) {
+        self.sample();
+        if self.since_restart >= self.guard_tick {
+            self.since_restart = 0;
+            self.status.restart();
+        }
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.sample();
+        self.status.refresh();
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.status.poll_count()
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.status.last_slept()
+    }
+
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        self.status.is_reusable()
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        self.status.elapsed()
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        self.status.remaining()
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        self.status.timeout_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockInstant, MockMtime};
+    use fugit::ExtU32;
+
+    #[test]
+    fn debounce() {
+        let w = DebouncedWaiter::<_, MockInstant>::new(
+            TickWaiter::<MockInstant, _, _>::us(
+                100.micros(),
+                NonInterval::new(),
+                MockInstant::FREQUENCY,
+            ),
+            30.micros(),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        MockInstant::advance(40);
+        t.restart();
+        MockInstant::advance(10);
+        // Within the window, it counts as the same restart.
+        t.restart();
+        MockInstant::advance(89);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+
+        t.restart();
+        MockInstant::advance(99);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+    }
+
+    #[test]
+    fn counter_wraparound() {
+        let w = DebouncedWaiter::<_, MockInstant>::new(
+            TickWaiter::<MockInstant, _, _>::us(
+                100.micros(),
+                NonInterval::new(),
+                MockInstant::FREQUENCY,
+            ),
+            1_000.micros(),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        MockInstant::advance(u32::MAX);
+        assert!(t.timeout());
+        // One full counter period plus one tick since `start()`.
+        MockInstant::advance(2);
+        t.restart();
+        assert!(!t.timeout());
+        assert_eq!(t.elapsed(), Some(0.micros()));
+    }
+
+    #[test]
+    fn any_frequency() {
+        let w = DebouncedWaiter::<_, MockMtime>::new(
+            Counter::new(10),
+            100.micros(),
+            MockMtime::FREQUENCY,
+        );
+        assert_eq!(w.guard_tick, 4);
+        let w = DebouncedWaiter::<_, MockInstant>::new(Counter::new(10), 5.secs(), 1_000_000_000);
+        assert_eq!(w.guard_tick, u32::MAX);
+    }
+}
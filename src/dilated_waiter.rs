@@ -0,0 +1,92 @@
+use super::*;
+
+/// Scale the timeout of a [`TickWaiter`] by `num / den`, for tests and simulation.
+///
+/// For example, `1 / 10` makes every wait 10 times faster,
+/// so timing-sensitive code can be tested without changing each timeout.
+/// The scaling is applied to the timeout ticks at `start()`, and saturates at `N::MAX`.
+///
+/// Needs the `test-util` feature enabled. It's not intended for production.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, DilatedWaiter, NonInterval, TickWaiter};
+///
+/// let w = DilatedWaiter::new(
+///     TickWaiter::<Instant, _, _>::ms(
+///         1000.millis(),
+///         NonInterval::new(),
+///         Duration::from_secs(1).as_nanos() as u32,
+///     ),
+///     1,
+///     100,
+/// );
+/// let t = Instant::now();
+/// let mut s = w.start();
+/// while !s.timeout() {}
+/// assert!(t.elapsed() < Duration::from_millis(100));
+/// ```
+pub struct DilatedWaiter<T, I, N> {
+    waiter: TickWaiter<T, I, N>,
+    num: u32,
+    den: u32,
+}
+
+impl<T, I, N> DilatedWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    /// The effective timeout is `timeout * num / den`.
+    pub fn new(waiter: TickWaiter<T, I, N>, num: u32, den: u32) -> Self {
+        assert!(den > 0);
+        Self { waiter, num, den }
+    }
+}
+
+impl<T, I, N> Waiter for DilatedWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> TickWaiterStatus<T, &I, N> {
+        let timeout_tick =
+            self.waiter.timeout_tick.as_u64() as u128 * self.num as u128 / self.den as u128;
+        let timeout_tick = u64::try_from(timeout_tick)
+            .ok()
+            .and_then(N::from_u64)
+            .unwrap_or(N::MAX);
+        TickWaiterStatus::new(timeout_tick, self.waiter.frequency, &self.waiter.interval)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use fugit::ExtU32;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn speedup() {
+        let w = DilatedWaiter::new(
+            TickWaiter::<Instant, _, _>::ms(
+                100.millis(),
+                StdInterval::new(Duration::ZERO),
+                Duration::from_secs(1).as_nanos() as u32,
+            ),
+            1,
+            10,
+        );
+        let start = Instant::now();
+        let mut t = w.start();
+        while !t.timeout() {}
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(10));
+        assert!(elapsed < Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,129 @@
+use super::*;
+use core::marker::PhantomData;
+
+/// [`Waiter`] implementation for a tick source whose frequency changes at runtime,
+/// e.g. with dynamic voltage and frequency scaling.
+///
+/// The timeout is kept in real time, and converted to ticks with the frequency
+/// returned by `frequency` at each `start()`. So the timeout stays the same across
+/// frequency changes between waits. The frequency is only read at `start()`,
+/// a change during a wait isn't taken into account. `restart()` on the returned status
+/// keeps the frequency read at `start()`, call `start()` again to pick up a new one.
+///
+/// The timeout is rounded up to whole ticks and saturates at `N::MAX` ticks.
+///
+/// # Panics
+///
+/// `start()` panics if `frequency` returns `0`.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use core::time::Duration;
+/// use waiter_trait::{DvfsWaiter, NonInterval, prelude::*, tick_instant};
+///
+/// // Stands in for the cycle counter, clocked by the core clock.
+/// static CYCLES: AtomicU32 = AtomicU32::new(0);
+/// tick_instant!(struct CycleTick(u32), || CYCLES.load(Ordering::Relaxed));
+///
+/// // The core clock frequency, kept up to date by the clock-change routine.
+/// static CORE_CLOCK: AtomicU32 = AtomicU32::new(8_000_000);
+///
+/// fn set_core_clock(hz: u32) {
+///     // Reprogram the PLL here.
+///     CORE_CLOCK.store(hz, Ordering::Relaxed);
+/// }
+///
+/// fn core_clock() -> u32 {
+///     CORE_CLOCK.load(Ordering::Relaxed)
+/// }
+///
+/// let w = DvfsWaiter::<CycleTick, _, u32>::new(Duration::from_millis(1), NonInterval::new(), core_clock);
+/// assert_eq!(w.start().deadline_ticks(), 8_000);
+///
+/// set_core_clock(48_000_000);
+/// assert_eq!(w.start().deadline_ticks(), 48_000);
+/// ```
+pub struct DvfsWaiter<T, I, N> {
+    timeout: Timeout,
+    interval: I,
+    frequency: fn() -> u32,
+    _t: PhantomData<(T, N)>,
+}
+
+impl<T, I, N> DvfsWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    /// - `timeout`
+    /// - `interval`: Before the time limit expires,
+    ///   this action will execute each time `timeout()` is called.
+    /// - `frequency`: returns the current frequency of `T`.
+    pub fn new(timeout: impl Into<Timeout>, interval: I, frequency: fn() -> u32) -> Self {
+        Self {
+            timeout: timeout.into(),
+            interval,
+            frequency,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T, I, N> Waiter for DvfsWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> TickWaiterStatus<T, &I, N> {
+        let frequency = (self.frequency)();
+        assert!(frequency > 0);
+        let timeout_tick = us_to_ticks(self.timeout.micros().ticks(), frequency);
+        TickWaiterStatus::new(timeout_tick, frequency, &self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use core::cell::Cell;
+    use fugit::{ExtU32, MillisDurationU32};
+
+    std::thread_local! {
+        static FREQUENCY: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn frequency() -> u32 {
+        FREQUENCY.with(|f| f.get())
+    }
+
+    #[test]
+    fn rescale_at_start() {
+        let w = DvfsWaiter::<MockInstant, _, u32>::new(
+            MillisDurationU32::from_ticks(1),
+            NonInterval::new(),
+            frequency,
+        );
+
+        FREQUENCY.with(|f| f.set(8_000_000));
+        let mut t = w.start();
+        assert_eq!(t.deadline_ticks(), 8_000);
+
+        // It doesn't affect a wait already started.
+        FREQUENCY.with(|f| f.set(48_000_000));
+        assert_eq!(t.deadline_ticks(), 8_000);
+        MockInstant::advance(8_000);
+        assert!(t.timeout());
+        t.restart();
+        assert_eq!(t.deadline_ticks(), 8_000);
+
+        let t = w.start();
+        assert_eq!(t.deadline_ticks(), 48_000);
+        assert_eq!(t.timeout_duration(), Some(1_000.micros()));
+    }
+}
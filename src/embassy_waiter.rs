@@ -0,0 +1,37 @@
+use super::*;
+use embassy_time::{Duration, Timer};
+
+/// [`AsyncTimedWaiter`] implementation backed by [`embassy_time::Timer`].
+/// Needs the `embassy-time` feature enabled.
+///
+/// The future sleeps on the timer instead of polling, so no CPU time is spent while waiting.
+/// The timeout is converted to [`embassy_time::Duration`] with
+/// [`Duration::from_micros()`], which rounds up to the tick rate of `embassy-time`.
+///
+/// It requires an `embassy-time` driver, usually provided by the HAL crate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use waiter_trait::{async_prelude::*, EmbassyWaiter};
+///
+/// async fn wait() {
+///     let w = EmbassyWaiter::new();
+///     w.start(10.millis()).await;
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmbassyWaiter;
+
+impl EmbassyWaiter {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl AsyncTimedWaiter for EmbassyWaiter {
+    #[inline]
+    fn start(&self, timeout: MicrosDurationU32) -> impl Future<Output = ()> {
+        Timer::after(Duration::from_micros(timeout.ticks() as u64))
+    }
+}
@@ -0,0 +1,23 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutError {
+    /// The frequency isn't supported by the conversion.
+    InvalidFrequency,
+    /// The timeout in ticks doesn't fit in the tick counter.
+    Overflow,
+    /// The interval sleeps too long compared to the timeout.
+    IntervalTooLong,
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFrequency => f.write_str("invalid frequency"),
+            Self::Overflow => f.write_str("timeout overflow"),
+            Self::IntervalTooLong => f.write_str("interval too long for the timeout"),
+        }
+    }
+}
+
+impl core::error::Error for TimeoutError {}
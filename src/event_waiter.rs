@@ -0,0 +1,224 @@
+use super::*;
+use core::sync::atomic::{AtomicBool, Ordering};
+use fugit::MicrosDurationU32;
+
+/// Wait for an event or a timeout, whichever comes first.
+///
+/// It wraps a [`Waiter`] or [`TimedWaiter`] and a flag set by the event source,
+/// e.g. an interrupt handler storing `true`. `timeout()` returns `true` when the flag is set
+/// or the inner status expires, and [`outcome()`](EventStatus::outcome) tells which.
+/// It's allocation-free.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicBool, Ordering};
+/// use waiter_trait::{prelude::*, Counter, EventWaiter, WaitOutcome};
+///
+/// static READY: AtomicBool = AtomicBool::new(false);
+///
+/// let w = EventWaiter::new(Counter::new(10), &READY);
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// READY.store(true, Ordering::Release);
+/// assert!(t.timeout());
+/// assert_eq!(t.outcome(), Some(WaitOutcome::Event));
+/// ```
+pub struct EventWaiter<'a, W> {
+    waiter: W,
+    event: &'a AtomicBool,
+}
+
+impl<'a, W> EventWaiter<'a, W> {
+    /// - `waiter`: the inner waiter for the timeout.
+    /// - `event`: set to `true` by the event source.
+    pub fn new(waiter: W, event: &'a AtomicBool) -> Self {
+        Self { waiter, event }
+    }
+}
+
+impl<'a, W: Waiter> Waiter for EventWaiter<'a, W> {
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> EventStatus<'a, impl WaiterStatus> {
+        EventStatus {
+            status: self.waiter.start(),
+            event: self.event,
+            outcome: None,
+            poll_count: 0,
+        }
+    }
+}
+
+impl<'a, W: TimedWaiter> TimedWaiter for EventWaiter<'a, W> {
+    #[allow(refining_impl_trait)]
+    fn start(&self, timeout: MicrosDurationU32) -> EventStatus<'a, impl WaiterStatus> {
+        EventStatus {
+            status: self.waiter.start(timeout),
+            event: self.event,
+            outcome: None,
+            poll_count: 0,
+        }
+    }
+}
+
+/// What ended an [`EventWaiter`] wait.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WaitOutcome {
+    /// The event flag was set.
+    Event,
+    /// The time limit expired.
+    Timeout,
+}
+
+/// [`WaiterStatus`] returned by [`EventWaiter`].
+///
+/// Each poll checks the flag first, then polls the inner status. So if the event and
+/// the deadline coincide, the event wins as long as the flag is set before the poll
+/// reads it, otherwise the poll reports [`WaitOutcome::Timeout`].
+///
+/// The flag is read with [`Ordering::Acquire`], so writes made by the event source
+/// before storing `true` with [`Ordering::Release`] are visible once the event is seen.
+/// Then it's cleared with a plain store rather than a swap, so it works on targets
+/// without atomic read-modify-write, such as Cortex-M0. An event set again between
+/// the read and the clear is merged into the one seen.
+///
+/// Once it returns `true`, it keeps returning `true` until `restart()` is called.
+/// The inner status isn't polled anymore then, but [`poll_count()`](WaiterStatus::poll_count)
+/// still counts every call.
+pub struct EventStatus<'a, S> {
+    status: S,
+    event: &'a AtomicBool,
+    outcome: Option<WaitOutcome>,
+    poll_count: u32,
+}
+
+impl<S> EventStatus<'_, S> {
+    /// What ended the wait, or `None` if it's still waiting.
+    #[inline(always)]
+    pub fn outcome(&self) -> Option<WaitOutcome> {
+        self.outcome
+    }
+
+    fn take_event(&mut self) -> bool {
+        if self.event.load(Ordering::Acquire) {
+            self.event.store(false, Ordering::Relaxed);
+            self.outcome = Some(WaitOutcome::Event);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<S: WaiterStatus> WaiterStatus for EventStatus<'_, S> {
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        self.poll_count = self.poll_count.saturating_add(1);
+        if self.outcome.is_some() || self.take_event() {
+            return true;
+        }
+        if self.status.timeout() {
+            self.outcome = Some(WaitOutcome::Timeout);
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.poll_count = self.poll_count.saturating_add(1);
+        if self.outcome.is_some() || self.take_event() {
+            return true;
+        }
+        if self.status.check() {
+            self.outcome = Some(WaitOutcome::Timeout);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The event flag isn't cleared.
+    #[inline]
+    fn restart(&mut self) {
+        self.outcome = None;
+        self.poll_count = 0;
+        self.status.restart();
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.status.refresh();
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.outcome.is_none() && self.status.last_slept()
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        self.status.elapsed()
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        self.status.remaining()
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        self.status.timeout_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use fugit::ExtU32;
+
+    #[test]
+    fn event_or_timeout() {
+        let event = AtomicBool::new(false);
+        let w = EventWaiter::new(
+            TickWaiter::<MockInstant, _, _>::us(
+                100.micros(),
+                NonInterval::new(),
+                MockInstant::FREQUENCY,
+            ),
+            &event,
+        );
+
+        // Signaled mid-wait.
+        let mut t = w.start();
+        MockInstant::advance(40);
+        assert!(!t.timeout());
+        assert_eq!(t.outcome(), None);
+        event.store(true, Ordering::Release);
+        assert!(t.timeout());
+        assert_eq!(t.outcome(), Some(WaitOutcome::Event));
+        assert!(!event.load(Ordering::Relaxed));
+        MockInstant::advance(100);
+        assert!(t.timeout());
+        assert_eq!(t.outcome(), Some(WaitOutcome::Event));
+        assert_eq!(t.poll_count(), 3);
+
+        // Times out.
+        t.restart();
+        MockInstant::advance(99);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+        assert_eq!(t.outcome(), Some(WaitOutcome::Timeout));
+        // A late event doesn't change the outcome.
+        event.store(true, Ordering::Release);
+        assert!(t.timeout());
+        assert!(t.check());
+        assert_eq!(t.outcome(), Some(WaitOutcome::Timeout));
+        assert_eq!(t.poll_count(), 4);
+    }
+}
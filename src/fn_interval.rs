@@ -0,0 +1,55 @@
+use super::*;
+use core::cell::RefCell;
+
+/// [`Interval`] implementation that calls a closure.
+///
+/// It's useful to poll an async executor or yield to a cooperative scheduler
+/// while blocking in a wait. It's only a stopgap in async code, an async waiter is preferred.
+///
+/// [`Interval::interval`] takes `&self`, so the closure is kept in a [`RefCell`] to allow `FnMut`.
+/// The closure must not call `interval()` of the same `FnInterval` again.
+/// [`Interval`] requires `Clone`, so the closure must be `Clone` as well,
+/// for example, a `fn` or a closure capturing only references.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use std::time::Duration;
+/// use waiter_trait::{prelude::*, FnInterval, StdWaiter};
+///
+/// static POLLS: AtomicU32 = AtomicU32::new(0);
+///
+/// fn poll_executor() {
+///     POLLS.fetch_add(1, Ordering::Relaxed);
+///     std::thread::sleep(Duration::from_millis(1));
+/// }
+///
+/// let w = StdWaiter::new(Duration::from_millis(10), FnInterval::new(poll_executor));
+/// let mut t = w.start();
+/// while !t.timeout() {}
+/// assert!(POLLS.load(Ordering::Relaxed) > 0);
+/// ```
+#[derive(Clone)]
+pub struct FnInterval<F> {
+    f: RefCell<F>,
+}
+
+impl<F> FnInterval<F>
+where
+    F: FnMut() + Clone,
+{
+    pub const fn new(f: F) -> Self {
+        Self { f: RefCell::new(f) }
+    }
+}
+
+impl<F> Interval for FnInterval<F>
+where
+    F: FnMut() + Clone,
+{
+    #[inline]
+    fn interval(&self) {
+        (self.f.borrow_mut())();
+    }
+}
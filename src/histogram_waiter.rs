@@ -0,0 +1,221 @@
+use super::*;
+use core::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// Record a histogram of the actual elapsed time each time the inner waiter expires.
+///
+/// Bucket `i` counts the expiries with elapsed time in `[i * bucket_width, (i + 1) * bucket_width)`,
+/// the last bucket also counts everything longer. The elapsed time is measured with
+/// [`Instant`] from `start()`, `restart()` or the previous expiry.
+/// An expiry is recorded once: polling a latched status again doesn't add samples,
+/// while a [reusable](WaiterStatus::is_reusable) status records each period.
+///
+/// It reveals the overshoot and jitter over a test run.
+/// It's intended for analysis on a host, not for production.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use waiter_trait::{prelude::*, HistogramWaiter, NonInterval, StdWaiter};
+///
+/// let mut w = HistogramWaiter::<_, 4>::new(
+///     StdWaiter::new(Duration::from_millis(5), NonInterval::new()),
+///     Duration::from_millis(5),
+/// );
+/// {
+///     let mut t = w.start();
+///     while !t.timeout() {}
+/// }
+/// assert_eq!(w.histogram()[0], 0);
+/// assert_eq!(w.histogram().iter().sum::<u32>(), 1);
+/// ```
+pub struct HistogramWaiter<W, const N: usize> {
+    waiter: W,
+    bucket_width: Duration,
+    histogram: Cell<[u32; N]>,
+}
+
+impl<W, const N: usize> HistogramWaiter<W, N> {
+    /// - `waiter`: the inner waiter.
+    /// - `bucket_width`: the width of each of the `N` buckets.
+    pub fn new(waiter: W, bucket_width: Duration) -> Self {
+        assert!(N > 0 && !bucket_width.is_zero());
+        Self {
+            waiter,
+            bucket_width,
+            histogram: Cell::new([0; N]),
+        }
+    }
+
+    /// The count of expiries in each bucket.
+    ///
+    /// It takes `&mut self`, so no status can record while the reference is alive.
+    pub fn histogram(&mut self) -> &[u32; N] {
+        self.histogram.get_mut()
+    }
+
+    /// Clear all buckets.
+    pub fn clear(&self) {
+        self.histogram.set([0; N]);
+    }
+
+    fn record(&self, elapsed: Duration) {
+        let i = (elapsed.as_nanos() / self.bucket_width.as_nanos()).min(N as u128 - 1) as usize;
+        let mut histogram = self.histogram.get();
+        histogram[i] = histogram[i].saturating_add(1);
+        self.histogram.set(histogram);
+    }
+}
+
+impl<W: Waiter, const N: usize> Waiter for HistogramWaiter<W, N> {
+    fn start(&self) -> impl WaiterStatus {
+        // Take the time first, so the recorded time isn't shorter than the inner one.
+        let start_time = Instant::now();
+        HistogramStatus {
+            status: self.waiter.start(),
+            start_time,
+            expired: false,
+            waiter: self,
+        }
+    }
+}
+
+impl<W: TimedWaiter, const N: usize> TimedWaiter for HistogramWaiter<W, N> {
+    fn start(&self, timeout: MicrosDurationU32) -> impl WaiterStatus {
+        // Take the time first, so the recorded time isn't shorter than the inner one.
+        let start_time = Instant::now();
+        HistogramStatus {
+            status: self.waiter.start(timeout),
+            start_time,
+            expired: false,
+            waiter: self,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`HistogramWaiter`].
+pub struct HistogramStatus<'a, S, W, const N: usize> {
+    status: S,
+    start_time: Instant,
+    expired: bool,
+    waiter: &'a HistogramWaiter<W, N>,
+}
+
+impl<'a, S: WaiterStatus, W, const N: usize> HistogramStatus<'a, S, W, N> {
+    fn record(&mut self, expired: bool) -> bool {
+        if expired && (!self.expired || self.status.is_reusable()) {
+            let now = Instant::now();
+            self.waiter.record(now - self.start_time);
+            self.start_time = now;
+        }
+        self.expired = expired;
+        expired
+    }
+}
+
+impl<'a, S: WaiterStatus, W, const N: usize> WaiterStatus for HistogramStatus<'a, S, W, N> {
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        let expired = self.status.timeout();
+        self.record(expired)
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        let expired = self.status.check();
+        self.record(expired)
+    }
+
+    #[inline]
+    fn restart(&mut self) {
+        self.start_time = Instant::now();
+        self.expired = false;
+        self.status.restart();
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.status.poll_count()
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.status.last_slept()
+    }
+
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        self.status.is_reusable()
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.status.refresh();
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        self.status.elapsed()
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        self.status.remaining()
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        self.status.timeout_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram() {
+        let mut w = HistogramWaiter::<_, 4>::new(
+            TickWaiter::<Instant, _, _>::us(
+                MicrosDurationU32::millis(5),
+                StdInterval::new(Duration::ZERO),
+                Duration::from_secs(1).as_nanos() as u32,
+            ),
+            Duration::from_millis(5),
+        );
+        {
+            let mut t = w.start();
+            for _ in 0..10 {
+                while !t.timeout() {}
+                t.restart();
+            }
+        }
+        let histogram = w.histogram();
+        assert_eq!(histogram[0], 0);
+        assert!(histogram[1] > 0);
+        assert_eq!(histogram.iter().sum::<u32>(), 10);
+
+        w.clear();
+        assert_eq!(w.histogram(), &[0; 4]);
+    }
+
+    #[test]
+    fn latched() {
+        let mut w = HistogramWaiter::<_, 4>::new(Counter::new(1), Duration::from_secs(1));
+        {
+            let mut t = w.start();
+            assert!(!t.timeout());
+            for _ in 0..5 {
+                assert!(t.timeout());
+            }
+            assert!(t.check());
+        }
+        assert_eq!(w.histogram(), &[1, 0, 0, 0]);
+
+        {
+            let mut t = w.start();
+            while !t.timeout() {}
+            t.restart();
+            while !t.timeout() {}
+        }
+        assert_eq!(w.histogram(), &[3, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+use core::cell::Cell;
+use fugit::MicrosDurationU32;
+
+/// Perturb the timeout of a [`TimedWaiter`] by a bounded random amount, for tests.
+///
+/// At each `start()`, an offset within `-tolerance..=tolerance` is added to the timeout,
+/// saturating at `0` and `u32::MAX` microseconds. So a wait may expire early or late,
+/// but never by more than `tolerance`. It lets tests check that a state machine doesn't
+/// assume exact timing.
+///
+/// The offsets come from a xorshift PRNG seeded with `seed`,
+/// so the same seed always gives the same sequence.
+///
+/// Needs the `test-util` feature enabled. It's not intended for production.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, JitteredWaiter, NonInterval, TimedTickWaiter};
+///
+/// let w = JitteredWaiter::new(
+///     TimedTickWaiter::<Instant, _>::new(
+///         NonInterval::new(),
+///         Duration::from_secs(1).as_nanos() as u32,
+///     ),
+///     2.millis(),
+///     42,
+/// );
+/// let t = w.start(10.millis());
+/// let timeout = t.timeout_duration().unwrap().ticks();
+/// assert!((8_000..=12_000).contains(&timeout));
+/// ```
+pub struct JitteredWaiter<W> {
+    waiter: W,
+    tolerance: u32,
+    state: Cell<u32>,
+}
+
+impl<W: TimedWaiter> JitteredWaiter<W> {
+    /// - `waiter`: the inner waiter.
+    /// - `tolerance`: the largest offset added to or subtracted from the timeout.
+    /// - `seed`: the seed of the PRNG.
+    pub fn new(waiter: W, tolerance: MicrosDurationU32, seed: u32) -> Self {
+        Self {
+            waiter,
+            tolerance: tolerance.ticks(),
+            // xorshift gets stuck at zero.
+            state: Cell::new(if seed == 0 { 0x9E37_79B9 } else { seed }),
+        }
+    }
+
+    fn next_offset(&self) -> i64 {
+        let mut x = self.state.get();
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state.set(x);
+        let span = self.tolerance as u64 * 2 + 1;
+        (x as u64 % span) as i64 - self.tolerance as i64
+    }
+}
+
+impl<W: TimedWaiter> TimedWaiter for JitteredWaiter<W> {
+    #[allow(refining_impl_trait)]
+    fn start(&self, timeout: MicrosDurationU32) -> JitteredStatus<impl WaiterStatus> {
+        let offset = self.next_offset();
+        let jittered = (timeout.ticks() as i64 + offset).clamp(0, u32::MAX as i64) as u32;
+        JitteredStatus {
+            status: self.waiter.start(MicrosDurationU32::from_ticks(jittered)),
+            offset: jittered as i64 - timeout.ticks() as i64,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`JitteredWaiter`].
+pub struct JitteredStatus<S> {
+    status: S,
+    offset: i64,
+}
+
+impl<S> JitteredStatus<S> {
+    /// The offset added to the timeout in microseconds, after saturation.
+    pub fn offset_us(&self) -> i64 {
+        self.offset
+    }
+}
+
+impl<S: WaiterStatus> WaiterStatus for JitteredStatus<S> {
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        self.status.timeout()
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.status.check()
+    }
+
+    /// The offset drawn at `start()` is kept.
+    #[inline]
+    fn restart(&mut self) {
+        self.status.restart();
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.status.refresh();
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.status.poll_count()
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.status.last_slept()
+    }
+
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        self.status.is_reusable()
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        self.status.elapsed()
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        self.status.remaining()
+    }
+
+    /// The jittered timeout.
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        self.status.timeout_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use fugit::ExtU32;
+
+    #[test]
+    fn within_tolerance() {
+        let new = || {
+            JitteredWaiter::new(
+                TimedTickWaiter::<MockInstant, _>::new(NonInterval::new(), MockInstant::FREQUENCY),
+                20.micros(),
+                7,
+            )
+        };
+        let (a, b) = (new(), new());
+        let mut early = false;
+        let mut late = false;
+        for _ in 0..100 {
+            let mut t = a.start(100.micros());
+            let timeout = t.timeout_duration().unwrap().ticks();
+            assert!((80..=120).contains(&timeout));
+            early |= timeout < 100;
+            late |= timeout > 100;
+
+            // Deterministic for the same seed.
+            assert_eq!(
+                b.start(100.micros()).timeout_duration(),
+                Some(timeout.micros())
+            );
+
+            MockInstant::advance(timeout - 1);
+            assert!(!t.timeout());
+            MockInstant::advance(1);
+            assert!(t.timeout());
+        }
+        assert!(early && late);
+
+        // Saturates at zero.
+        let w = JitteredWaiter::new(
+            TimedTickWaiter::<MockInstant, _>::new(NonInterval::new(), MockInstant::FREQUENCY),
+            20.micros(),
+            7,
+        );
+        for _ in 0..100 {
+            let t = w.start(5.micros());
+            assert!(t.timeout_duration().unwrap().ticks() <= 25);
+            assert!(t.offset_us() >= -5);
+        }
+    }
+}
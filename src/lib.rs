@@ -3,6 +3,10 @@
 //! # Features
 //!
 //!- `std`: Disabled by default.
+//!- `async`: Disabled by default. Async traits in [`async_prelude`].
+//!- `cortex-m`: Disabled by default. [`SysTickInterval`] sleeping with a SysTick delay.
+//!- `embassy-time`: Disabled by default. [`EmbassyWaiter`] based on `embassy-time`. Enables `async`.
+//!- `test-util`: Disabled by default. Utilities for tests and simulation, such as [`DilatedWaiter`].
 //!
 //! # Usage
 //!
@@ -36,59 +40,407 @@
 //! ## Pre-implemented
 //!
 //! - [`StdWaiter`] and [`StdInterval`]: Need the `std` feature enabled.
+//! - [`EscalatingInterval`]: yields then sleeps. Needs the `std` feature enabled.
+//! - [`HistogramWaiter`]: records a histogram of the elapsed time at expiry. Needs the `std` feature enabled.
 //! - [`NonInterval`]: implements [`Interval`] that does nothing.
+//! - [`FnInterval`]: implements [`Interval`] that calls a closure.
 //! - [`TickDelay`]: implements [`DelayNs`]
+//! - [`DebouncedWaiter`]: ignores `restart()` calls within a guard window.
+//! - [`DvfsWaiter`]: keeps the timeout in real time when the tick frequency changes.
+//! - [`EventWaiter`]: waits for an event flag or a timeout.
+//! - [`LongWaiter`]: for timeouts of hours or days.
+//! - [`SequencedWaiter`]: goes through a sequence of phases.
+//! - [`MultiDeadline`]: waits on several deadlines at once.
+//! - [`RateLimitedWaiter`]: limits how often the inner status is polled.
+//! - [`TickMoment`]: a monotonic instant built on [`TickInstant`].
+//! - [`EmbassyWaiter`]: implements [`AsyncTimedWaiter`] with `embassy-time`. Needs the `embassy-time` feature enabled.
+//! - [`DilatedWaiter`] and [`JitteredWaiter`]: scale or perturb timeouts in tests. Need the `test-util` feature enabled.
+//! - [`SysTickInterval`]: implements [`Interval`] that sleeps with a SysTick delay. Needs the `cortex-m` feature enabled.
+//! - [`WindowedWatchdogInterval`]: implements [`Interval`] that feeds a window watchdog.
 //!
 //! # Implement Your Own
 //!
 //! For developers, you can choose one of the following options.
 //! - Implement [`Waiter`] or [`TimedWaiter`], and [`WaiterStatus`] then use them.
 //! - Implement [`TickInstant`] then use [`TickWaiter`] or [`TimedTickWaiter`].
+//!     - Or generate it from a counter read with [`tick_instant!`].
+//!     - Or register a `u32` counter with [`WrappingTick::set_source`] and use [`WrappingTick`].
 //!     - Simply give [`NonInterval`] to `Waiter`, If you don't need interval.
 //!       In this way, you can also use [`DelayNs`] or `sleep` separately.
 //!     - Or you can implement [`Interval`] and use it.
+//! - Using [`TimestampWaiter`], if you have a function returning a timestamp in microseconds.
 //! - Using [`Counter`], if you don't have any tick source.
 
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
+mod core_impls;
 mod counter;
 pub use counter::*;
+mod countdown_status;
+pub use countdown_status::*;
+mod debounced_waiter;
+pub use debounced_waiter::*;
+mod dvfs_waiter;
+pub use dvfs_waiter::*;
+mod error;
+pub use error::*;
+mod event_waiter;
+pub use event_waiter::*;
+mod fn_interval;
+pub use fn_interval::*;
+mod long_waiter;
+pub use long_waiter::*;
+mod macros;
+mod multi_deadline;
+pub use multi_deadline::*;
 mod non_interval;
 pub use non_interval::*;
+mod rate_limited_waiter;
+pub use rate_limited_waiter::*;
+mod sequenced_waiter;
+pub use sequenced_waiter::*;
 mod tick_waiter;
 pub use tick_waiter::*;
 mod tick_delay;
 pub use tick_delay::*;
+mod tick_moment;
+pub use tick_moment::*;
 mod timed_tick_waiter;
 pub use timed_tick_waiter::*;
+mod timeout;
+pub use timeout::*;
+mod timestamp_waiter;
+pub use timestamp_waiter::*;
+mod watchdog_interval;
+pub use watchdog_interval::*;
+mod wrapping_tick;
+pub use wrapping_tick::*;
 
+#[cfg(any(test, feature = "test-util"))]
+mod dilated_waiter;
+#[cfg(any(test, feature = "test-util"))]
+pub use dilated_waiter::*;
+#[cfg(any(test, feature = "test-util"))]
+mod jittered_waiter;
+#[cfg(any(test, feature = "test-util"))]
+pub use jittered_waiter::*;
+
+#[cfg(feature = "cortex-m")]
+mod systick_interval;
+#[cfg(feature = "cortex-m")]
+pub use systick_interval::*;
+
+#[cfg(feature = "embassy-time")]
+mod embassy_waiter;
+#[cfg(feature = "embassy-time")]
+pub use embassy_waiter::*;
+
+#[cfg(feature = "std")]
+mod histogram_waiter;
+#[cfg(feature = "std")]
+pub use histogram_waiter::*;
 #[cfg(feature = "std")]
 mod std_impls;
 #[cfg(feature = "std")]
 pub use std_impls::*;
 
+use core::ops::ControlFlow;
 pub use embedded_hal::delay::DelayNs;
 pub use fugit::{self, MicrosDurationU32};
 
+#[cfg(feature = "async")]
+pub mod async_prelude;
 pub mod prelude;
 
+#[cfg(not(any(test, feature = "std")))]
+mod no_std_smoke;
+
+#[cfg(test)]
+mod mock;
+
 pub trait Waiter {
     /// Start waiting.
     fn start(&self) -> impl WaiterStatus;
 }
 
+/// The same as [`Waiter`], but `start()` takes `&mut self`.
+///
+/// Implement it for waiters that need mutable setup on each wait,
+/// such as lazily initializing a hardware timer or reconfiguring a compare channel.
+/// Otherwise, implement [`Waiter`], which can be shared.
+///
+/// # Examples
+///
+/// ```
+/// use waiter_trait::{prelude::*, Counter};
+///
+/// struct CompareWaiter {
+///     armed: u32,
+///     counter: Counter,
+/// }
+///
+/// impl WaiterMut for CompareWaiter {
+///     fn start(&mut self) -> impl WaiterStatus {
+///         // Arm the compare channel here.
+///         self.armed += 1;
+///         self.counter.start()
+///     }
+/// }
+///
+/// let mut w = CompareWaiter { armed: 0, counter: Counter::new(1) };
+/// {
+///     let mut t = w.start();
+///     assert!(!t.timeout());
+///     assert!(t.timeout());
+/// }
+/// assert_eq!(w.armed, 1);
+/// ```
+pub trait WaiterMut {
+    /// Set up and start waiting.
+    fn start(&mut self) -> impl WaiterStatus;
+}
+
 /// The difference from [`Waiter`] is that it supports setting timeout at `start()`.
 pub trait TimedWaiter {
     /// Set timeout and start waiting.
     fn start(&self, timeout: MicrosDurationU32) -> impl WaiterStatus;
 }
 
+/// Async version of [`TimedWaiter`]. Needs the `async` feature enabled.
+///
+/// Instead of polling a [`WaiterStatus`], the returned future completes when the time limit expires.
+#[cfg(feature = "async")]
+pub trait AsyncTimedWaiter {
+    /// Wait until `timeout` expires.
+    fn start(&self, timeout: MicrosDurationU32) -> impl Future<Output = ()>;
+}
+
+/// It's dyn compatible, see [`as_dyn()`](Self::as_dyn). Generic methods, such as the ones
+/// taking `impl Trait` arguments, must have a `where Self: Sized` bound to keep it so,
+/// otherwise they belong to a separate trait.
 pub trait WaiterStatus {
     /// Check if the time limit expires. This function may sleeps for a while,
     /// depends on the implementation.
     fn timeout(&mut self) -> bool;
     /// Reset the timeout condition.
     fn restart(&mut self);
+    /// The number of polls (`timeout()` or [`check()`](Self::check)) since `start()` or
+    /// [`restart()`](Self::restart), including the ones that return `true`.
+    /// It saturates at `u32::MAX`.
+    ///
+    /// The default implementation returns `0`, meaning polls aren't tracked.
+    fn poll_count(&self) -> u32 {
+        0
+    }
+    /// Whether the last [`timeout()`](Self::timeout) call ran the [`Interval`],
+    /// instead of returning `true` on expiry.
+    ///
+    /// It reflects that the interval was invoked, not how long it actually slept.
+    /// It's `false` after `start()` and [`restart()`](Self::restart),
+    /// and isn't changed by [`check()`](Self::check).
+    ///
+    /// The default implementation returns `false`, for implementations without an interval.
+    fn last_slept(&self) -> bool {
+        false
+    }
+    /// Whether polling again after an expiry re-arms the wait.
+    ///
+    /// - `true`: the next period starts at the expiry, so the status can be reused
+    ///   without calling [`restart()`](Self::restart).
+    /// - `false`: it latches, polling keeps returning `true` until `restart()` is called.
+    ///
+    /// The default implementation returns `false`.
+    fn is_reusable(&self) -> bool {
+        false
+    }
+    /// The same as [`timeout()`](Self::timeout), but never calls the [`Interval`].
+    ///
+    /// The default implementation calls `timeout()`,
+    /// implementations with an interval should override it.
+    fn check(&mut self) -> bool {
+        self.timeout()
+    }
+    /// The same as [`timeout()`](Self::timeout), but gives `cond` a last chance on expiry.
+    ///
+    /// It returns `true` only if the time limit expires and `cond` returns `false`.
+    /// `cond` is only called once the time limit expires, it's skipped otherwise.
+    /// It's useful for hardware setting its ready flag slightly after the deadline:
+    /// if `cond` returns `true`, it returns `false` so the caller can see the flag set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waiter_trait::{prelude::*, Counter};
+    ///
+    /// let c = Counter::new(0);
+    /// let mut t = c.start();
+    /// assert!(!t.timeout_with_grace(|| true));
+    /// assert!(t.timeout_with_grace(|| false));
+    /// ```
+    fn timeout_with_grace(&mut self, cond: impl FnOnce() -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        self.timeout() && !cond()
+    }
+    /// Erase the concrete type, e.g. to pass it to a non-generic function.
+    ///
+    /// It needs no allocation, so it works in `no_std`.
+    fn as_dyn(&mut self) -> &mut dyn WaiterStatus
+    where
+        Self: Sized,
+    {
+        self
+    }
+    /// Poll once and give control back to the caller immediately.
+    ///
+    /// Returns [`Break(())`](ControlFlow::Break) if the time limit expires,
+    /// otherwise [`Continue(())`](ControlFlow::Continue). It's based on [`check()`](Self::check),
+    /// so no interval or sleep happens in `step()`. It's useful for a cooperative scheduler
+    /// that polls many waiters round-robin and decides itself when to resume.
+    fn step(&mut self) -> ControlFlow<()> {
+        if self.check() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+    /// Call `work` between polls until the time limit expires.
+    ///
+    /// It replaces the interval of the waiter with `work` for this wait,
+    /// `work` runs once for each poll that doesn't expire.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waiter_trait::{prelude::*, Counter};
+    ///
+    /// let c = Counter::new(3);
+    /// let mut t = c.start();
+    /// let mut count = 0;
+    /// t.await_or_run(|| count += 1);
+    /// assert_eq!(count, 3);
+    /// ```
+    fn await_or_run(&mut self, mut work: impl FnMut())
+    where
+        Self: Sized,
+    {
+        while !self.check() {
+            work();
+        }
+    }
+    /// Call `poll` repeatedly and collect every `Some` into `out` until the time limit expires.
+    ///
+    /// It returns when the time limit expires, not when `poll` returns `None`.
+    /// In `no_std`, `out` can be anything implementing [`Extend`], such as a `heapless::Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waiter_trait::{prelude::*, Counter};
+    ///
+    /// let c = Counter::new(4);
+    /// let mut t = c.start();
+    /// let mut n = 0;
+    /// let mut out = Vec::new();
+    /// t.collect_until(
+    ///     || {
+    ///         n += 1;
+    ///         (n % 2 == 0).then_some(n)
+    ///     },
+    ///     &mut out,
+    /// );
+    /// assert_eq!(out, [2, 4]);
+    /// ```
+    fn collect_until<R>(&mut self, mut poll: impl FnMut() -> Option<R>, out: &mut impl Extend<R>)
+    where
+        Self: Sized,
+    {
+        loop {
+            if let Some(r) = poll() {
+                out.extend(Some(r));
+            }
+            if self.timeout() {
+                break;
+            }
+        }
+    }
+    /// Block for the time remaining, by calling [`delay_us()`](DelayNs::delay_us) on `delay`.
+    ///
+    /// It samples the clock first, then sleeps with the supplied `delay`, not the interval.
+    /// It does nothing if the time limit has already expired or the implementation
+    /// doesn't track time. It's useful to pad out a fixed-rate loop after the work is done.
+    ///
+    /// It doesn't poll, so call [`timeout()`](Self::timeout) afterwards to re-arm
+    /// a [reusable](Self::is_reusable) status.
+    fn sleep_remaining(&mut self, delay: &mut impl DelayNs)
+    where
+        Self: Sized,
+    {
+        self.refresh();
+        if let Some(remaining) = self.remaining()
+            && remaining.ticks() > 0
+        {
+            delay.delay_us(remaining.ticks());
+        }
+    }
+    /// Sample the clock without checking the time limit.
+    ///
+    /// The instant sampled by [`timeout()`](Self::timeout), [`restart()`](Self::restart)
+    /// or `refresh()` is cached. [`elapsed()`](Self::elapsed) and [`remaining()`](Self::remaining)
+    /// reuse it instead of reading the clock again, so they are consistent within one poll.
+    fn refresh(&mut self) {}
+    /// Time elapsed at the last sample.
+    /// Returns `None` if the implementation doesn't track time.
+    ///
+    /// Durations returned by `WaiterStatus` never overflow,
+    /// they saturate at `u32::MAX` microseconds (about 71 minutes).
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        None
+    }
+    /// Time remaining at the last sample.
+    /// Returns `None` if the implementation doesn't track time.
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        None
+    }
+    /// The configured timeout.
+    /// Returns `None` if the implementation doesn't track time.
+    ///
+    /// Tick based implementations convert it back from ticks with the frequency
+    /// given to the waiter, so it's rounded to the tick resolution.
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        None
+    }
+    /// Progress at the last sample in percent, from `0` to `100`.
+    /// Returns `None` if the implementation doesn't track time.
+    fn percent_done(&self) -> Option<u8> {
+        let timeout = self.timeout_duration()?.ticks() as u64;
+        let elapsed = self.elapsed()?.ticks() as u64;
+        Some(match timeout {
+            0 => 100,
+            timeout => (elapsed * 100 / timeout).min(100) as u8,
+        })
+    }
+    /// Draw a textual progress bar like `[####----] 50%` based on
+    /// [`percent_done()`](Self::percent_done).
+    ///
+    /// - `width`: the number of characters between the brackets.
+    ///
+    /// It doesn't read the clock. It writes nothing if the implementation doesn't track time.
+    fn render_progress(&self, w: &mut impl core::fmt::Write, width: usize) -> core::fmt::Result
+    where
+        Self: Sized,
+    {
+        let Some(percent) = self.percent_done() else {
+            return Ok(());
+        };
+        let done = width * percent as usize / 100;
+        w.write_char('[')?;
+        for i in 0..width {
+            w.write_char(if i < done { '#' } else { '-' })?;
+        }
+        write!(w, "] {percent}%")
+    }
 }
 
 pub trait TickInstant: Copy {
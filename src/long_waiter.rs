@@ -0,0 +1,166 @@
+use super::*;
+use core::time::Duration;
+use fugit::MicrosDurationU64;
+
+/// [`Waiter`] implementation for very long timeouts, such as hours or days.
+///
+/// It counts `u64` ticks, so the longest timeout is `u64::MAX` ticks,
+/// see [`max_horizon()`](Self::max_horizon). For example, it's about 584 years at 1 GHz.
+///
+/// [`TickInstant::tick_since`] returns `u32`, so `timeout()` must still be called
+/// at least once every `u32::MAX` ticks.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, LongWaiter, NonInterval};
+///
+/// let w = LongWaiter::<Instant, _>::new(
+///     Duration::from_secs(25 * 3600),
+///     NonInterval::new(),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// ```
+pub struct LongWaiter<T, I> {
+    waiter: TickWaiter<T, I, u64>,
+}
+
+impl<T, I> LongWaiter<T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// It supports any `frequency`, the timeout is rounded up to whole ticks.
+    pub fn new(timeout: Duration, interval: I, frequency: u32) -> Self {
+        Self::try_new(timeout, interval, frequency).unwrap()
+    }
+
+    pub fn us(timeout: MicrosDurationU64, interval: I, frequency: u32) -> Self {
+        Self::try_us(timeout, interval, frequency).unwrap()
+    }
+
+    /// The same as [`new()`](Self::new), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout is longer than [`max_horizon()`](Self::max_horizon).
+    pub fn try_new(timeout: Duration, interval: I, frequency: u32) -> Result<Self, TimeoutError> {
+        if frequency == 0 {
+            return Err(TimeoutError::InvalidFrequency);
+        }
+        // Converting through `u64` nanoseconds would cap it at about 584 years.
+        let ticks = timeout.as_secs() as u128 * frequency as u128
+            + (timeout.subsec_nanos() as u128 * frequency as u128).div_ceil(1_000_000_000);
+        let ticks = u64::try_from(ticks).map_err(|_| TimeoutError::Overflow)?;
+        Ok(Self {
+            waiter: TickWaiter::try_with(ticks, 1, 1, interval, frequency)?,
+        })
+    }
+
+    /// The same as [`us()`](Self::us), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout is longer than [`max_horizon()`](Self::max_horizon).
+    pub fn try_us(
+        timeout: MicrosDurationU64,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        if frequency == 0 {
+            return Err(TimeoutError::InvalidFrequency);
+        }
+        Ok(Self {
+            waiter: TickWaiter::try_with(
+                timeout.ticks(),
+                frequency,
+                1_000_000,
+                interval,
+                frequency,
+            )?,
+        })
+    }
+
+    /// The longest timeout at `frequency`.
+    ///
+    /// # Panics
+    ///
+    /// If `frequency` is `0`.
+    pub fn max_horizon(frequency: u32) -> Duration {
+        assert!(frequency > 0);
+        let frequency = frequency as u64;
+        Duration::new(
+            u64::MAX / frequency,
+            ((u64::MAX % frequency) * 1_000_000_000 / frequency) as u32,
+        )
+    }
+}
+
+impl<T, I> Waiter for LongWaiter<T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    #[inline]
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> TickWaiterStatus<T, &I, u64> {
+        self.waiter.start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+
+    #[test]
+    fn hours() {
+        const HOUR: u32 = 3_600_000_000;
+        let w = LongWaiter::<MockInstant, _>::new(
+            Duration::from_secs(25 * 3600),
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        for _ in 0..24 {
+            MockInstant::advance(HOUR);
+            assert!(!t.timeout());
+        }
+        MockInstant::advance(HOUR - 1);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+
+        assert_eq!(
+            LongWaiter::<MockInstant, NonInterval>::max_horizon(1_000_000),
+            Duration::from_micros(u64::MAX)
+        );
+        let w = LongWaiter::<MockInstant, _>::try_new(
+            Duration::from_micros(u64::MAX) + Duration::from_micros(1),
+            NonInterval::new(),
+            1_000_000,
+        );
+        assert_eq!(w.err(), Some(TimeoutError::Overflow));
+    }
+
+    #[test]
+    fn low_frequency_horizon() {
+        const F: u32 = 32_768;
+        let max = LongWaiter::<MockInstant, NonInterval>::max_horizon(F);
+        assert!(max > Duration::from_micros(u64::MAX));
+
+        let w = LongWaiter::<MockInstant, _>::try_new(max, NonInterval::new(), F);
+        assert_eq!(w.unwrap().waiter.timeout_tick, u64::MAX);
+        let w = LongWaiter::<MockInstant, _>::try_new(
+            max + Duration::from_secs(1),
+            NonInterval::new(),
+            F,
+        );
+        assert_eq!(w.err(), Some(TimeoutError::Overflow));
+    }
+}
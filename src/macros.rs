@@ -0,0 +1,78 @@
+/// Define a [`TickInstant`](crate::TickInstant) newtype over a hardware counter.
+///
+/// It takes the struct declaration, with the counter type, and an expression returning
+/// the current count, such as a closure or a function reading the timer register.
+/// The counter type must be an unsigned integer no wider than `u64`.
+///
+/// The generated `tick_since()` uses `wrapping_sub`, so it stays correct when the counter
+/// wraps around, as long as less than one full counter period elapses between two samples.
+/// A counter narrower than `u32`, such as a 16-bit timer, wraps at its own width.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicU16, Ordering};
+/// use waiter_trait::{TickInstant, tick_instant};
+///
+/// // Stands in for a 16-bit timer register.
+/// static COUNT: AtomicU16 = AtomicU16::new(0);
+///
+/// tick_instant!(
+///     /// 16-bit timer ticks.
+///     pub struct TimerTick(u16),
+///     || COUNT.load(Ordering::Relaxed)
+/// );
+///
+/// COUNT.store(u16::MAX - 1, Ordering::Relaxed);
+/// let t = TimerTick::now();
+/// COUNT.store(3, Ordering::Relaxed);
+/// assert_eq!(TimerTick::now().tick_since(t), 5);
+/// ```
+#[macro_export]
+macro_rules! tick_instant {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($ty:ty), $now:expr $(,)?) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis struct $name(pub $ty);
+
+        impl $crate::TickInstant for $name {
+            #[inline(always)]
+            fn now() -> Self {
+                Self(($now)())
+            }
+
+            #[inline(always)]
+            fn tick_since(self, earlier: Self) -> u32 {
+                self.0.wrapping_sub(earlier.0) as u32
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, *};
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static REG: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn read_reg() -> u32 {
+        REG.with(|r| r.get())
+    }
+
+    tick_instant!(struct RegTick(u32), read_reg);
+
+    #[test]
+    fn tick_instant() {
+        REG.with(|r| r.set(u32::MAX - 10));
+        let w = TickWaiter::<RegTick, _, u32>::us(20.micros(), NonInterval::new(), 1_000_000);
+        let mut t = w.start();
+        REG.with(|r| r.set(5));
+        assert!(!t.timeout());
+        assert_eq!(t.elapsed(), Some(16.micros()));
+        REG.with(|r| r.set(9));
+        assert!(t.timeout());
+    }
+}
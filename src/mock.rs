@@ -0,0 +1,81 @@
+//! A manually advanced tick source for tests.
+
+use super::*;
+use core::cell::Cell;
+
+std::thread_local! {
+    static NOW: Cell<u32> = const { Cell::new(0) };
+    static FORBID_READS: Cell<bool> = const { Cell::new(false) };
+    static MTIME: Cell<u64> = const { Cell::new(0) };
+}
+
+/// [`TickInstant`] counting at [`MockInstant::FREQUENCY`].
+/// The clock is per thread, so tests don't interfere with each other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MockInstant(u32);
+
+impl MockInstant {
+    pub const FREQUENCY: u32 = 1_000_000;
+
+    pub fn advance(ticks: u32) {
+        NOW.with(|n| n.set(n.get().wrapping_add(ticks)));
+    }
+
+    /// Make `now()` panic while `forbid` is `true`.
+    pub fn forbid_reads(forbid: bool) {
+        FORBID_READS.with(|f| f.set(forbid));
+    }
+}
+
+impl TickInstant for MockInstant {
+    fn now() -> Self {
+        assert!(!FORBID_READS.with(|f| f.get()), "clock read");
+        Self(NOW.with(|n| n.get()))
+    }
+
+    fn tick_since(self, earlier: Self) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+}
+
+/// 64-bit [`TickInstant`] counting at [`MockMtime::FREQUENCY`], like RISC-V `mtime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MockMtime(u64);
+
+impl MockMtime {
+    pub const FREQUENCY: u32 = 32_768;
+
+    pub fn set(ticks: u64) {
+        MTIME.with(|n| n.set(ticks));
+    }
+
+    pub fn advance(ticks: u64) {
+        MTIME.with(|n| n.set(n.get().wrapping_add(ticks)));
+    }
+}
+
+impl TickInstant for MockMtime {
+    fn now() -> Self {
+        Self(MTIME.with(|n| n.get()))
+    }
+
+    fn tick_since(self, earlier: Self) -> u32 {
+        self.0.wrapping_sub(earlier.0) as u32
+    }
+}
+
+/// Check that an expired `t` behaves as [`WaiterStatus::is_reusable()`] declares.
+/// The clock must be past the deadline, but not past the next one.
+pub fn assert_reuse_contract(t: &mut impl WaiterStatus) {
+    assert!(t.timeout());
+    assert_eq!(t.timeout(), !t.is_reusable());
+}
+
+/// [`DelayNs`] advancing [`MockInstant`] instead of sleeping.
+pub struct MockDelay;
+
+impl DelayNs for MockDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        MockInstant::advance(ns.div_ceil(1_000));
+    }
+}
@@ -0,0 +1,190 @@
+use super::*;
+use fugit::MicrosDurationU32;
+
+/// [`WaiterStatus`] for several independent deadlines sharing one clock read per poll.
+///
+/// Each entry is an `(id, deadline)` pair, with the deadline counted from `new()`
+/// or `restart()`. `timeout()` returns `true` once any deadline has passed,
+/// and [`expired_ids()`](Self::expired_ids) reports which ones.
+/// If several deadlines pass in the same poll, they are all reported.
+///
+/// It's one-shot: once expired, `timeout()` keeps returning `true`
+/// until `restart()` re-arms all deadlines.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, MultiDeadline, NonInterval};
+///
+/// let mut t = MultiDeadline::<Instant, _, 2>::new(
+///     [(1, 10.millis()), (2, 20.millis())],
+///     NonInterval::new(),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+/// while !t.timeout() {}
+/// assert!(t.expired_ids().eq([1]));
+/// ```
+pub struct MultiDeadline<T, I, const N: usize> {
+    tick: T,
+    elapsed_tick: u32,
+    deadlines: [(usize, u32); N],
+    frequency: u32,
+    poll_count: u32,
+    slept: bool,
+    interval: I,
+}
+
+impl<T, I, const N: usize> MultiDeadline<T, I, N>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// Start waiting.
+    ///
+    /// - `deadlines`: `(id, deadline)` pairs. Each deadline saturates at `u32::MAX` ticks.
+    /// - `interval`: Before any deadline passes,
+    ///   this action will execute each time `timeout()` is called.
+    /// - `frequency`: the frequency of `T`.
+    pub fn new(deadlines: [(usize, MicrosDurationU32); N], interval: I, frequency: u32) -> Self {
+        Self::try_new(deadlines, interval, frequency).unwrap()
+    }
+
+    /// The same as [`new()`](Self::new), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    pub fn try_new(
+        deadlines: [(usize, MicrosDurationU32); N],
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Ok(Self {
+            tick: T::now(),
+            elapsed_tick: 0,
+            deadlines: deadlines.map(|(id, d)| (id, us_to_ticks(d.ticks() as u64, frequency))),
+            frequency,
+            poll_count: 0,
+            slept: false,
+            interval,
+        })
+    }
+
+    /// The ids of the deadlines passed at the last sample, in the order given to `new()`.
+    pub fn expired_ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.deadlines
+            .iter()
+            .filter(|(_, d)| self.elapsed_tick >= *d)
+            .map(|(id, _)| *id)
+    }
+
+    #[inline]
+    fn first_deadline(&self) -> u32 {
+        self.deadlines.iter().map(|(_, d)| *d).min().unwrap_or(0)
+    }
+}
+
+impl<T, I, const N: usize> WaiterStatus for MultiDeadline<T, I, N>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        let expired = self.check();
+        interval_unless(expired, &self.interval, &mut self.slept)
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.refresh();
+        self.poll_count = self.poll_count.saturating_add(1);
+        self.elapsed_tick >= self.first_deadline()
+    }
+
+    #[inline]
+    fn restart(&mut self) {
+        self.tick = T::now();
+        self.elapsed_tick = 0;
+        self.poll_count = 0;
+        self.slept = false;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        let now = T::now();
+        self.elapsed_tick = self.elapsed_tick.saturating_add(now.tick_since(self.tick));
+        self.tick = now;
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.elapsed_tick as u64, self.frequency))
+    }
+
+    /// Time remaining until the first deadline.
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        let remaining_tick = self.first_deadline().saturating_sub(self.elapsed_tick);
+        Some(ticks_to_us(remaining_tick as u64, self.frequency))
+    }
+
+    /// The first deadline.
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.first_deadline() as u64, self.frequency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use fugit::ExtU32;
+
+    #[test]
+    fn staggered() {
+        let mut t = MultiDeadline::<MockInstant, _, 3>::new(
+            [(7, 30.micros()), (3, 10.micros()), (5, 30.micros())],
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        assert_eq!(t.timeout_duration(), Some(10.micros()));
+        MockInstant::advance(9);
+        assert!(!t.timeout());
+        assert_eq!(t.expired_ids().count(), 0);
+        assert_eq!(t.remaining(), Some(1.micros()));
+
+        MockInstant::advance(1);
+        assert!(t.timeout());
+        assert!(t.expired_ids().eq([3]));
+
+        MockInstant::advance(19);
+        assert!(t.timeout());
+        assert!(t.expired_ids().eq([3]));
+        // Both pass in the same poll.
+        MockInstant::advance(1);
+        assert!(t.timeout());
+        assert!(t.expired_ids().eq([7, 3, 5]));
+
+        t.restart();
+        assert!(!t.timeout());
+        assert_eq!(t.expired_ids().count(), 0);
+    }
+
+    #[test]
+    fn invalid_frequency() {
+        let t =
+            MultiDeadline::<MockInstant, _, 1>::try_new([(1, 10.micros())], NonInterval::new(), 0);
+        assert_eq!(t.err(), Some(TimeoutError::InvalidFrequency));
+    }
+}
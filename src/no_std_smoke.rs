@@ -0,0 +1,78 @@
+//! Minimal `no_std` usage of each feature.
+//!
+//! It's only compiled without `std`, so building with a feature enabled,
+//! e.g. `cargo build --features async`, checks that the feature works in `no_std`.
+//! Nothing here is called.
+
+#![allow(dead_code)]
+
+use crate::prelude::*;
+use crate::*;
+use fugit::MicrosDurationU64;
+
+tick_instant!(struct SmokeTick(u32), || 0);
+
+fn core() {
+    let w = TickWaiter::<SmokeTick, _, u32>::us(1.millis(), NonInterval::new(), 1_000_000);
+    let mut t = w.start();
+    let _ = t.timeout();
+
+    let w = LongWaiter::<SmokeTick, _>::us(
+        MicrosDurationU64::secs(3600),
+        NonInterval::new(),
+        1_000_000,
+    );
+    let _ = w.start().timeout();
+
+    let c = Counter::new(10);
+    let _ = c.start().timeout();
+}
+
+#[cfg(feature = "async")]
+mod async_feature {
+    use crate::async_prelude::*;
+
+    async fn wait(w: &impl AsyncTimedWaiter) {
+        w.start(1.millis()).await;
+    }
+}
+
+#[cfg(feature = "cortex-m")]
+mod cortex_m_feature {
+    use super::SmokeTick;
+    use crate::{prelude::*, *};
+    use core::cell::RefCell;
+    use cortex_m::delay::Delay;
+
+    fn systick(delay: &RefCell<Delay>) {
+        let w = TimedTickWaiter::<SmokeTick, _>::new(SysTickInterval::new(delay, 10), 1_000_000);
+        let _ = w.start(1.millis()).timeout();
+    }
+}
+
+#[cfg(feature = "embassy-time")]
+mod embassy_time_feature {
+    use crate::{EmbassyWaiter, async_prelude::*};
+
+    async fn wait() {
+        EmbassyWaiter::new().start(1.millis()).await;
+    }
+}
+
+#[cfg(feature = "test-util")]
+mod test_util_feature {
+    use super::SmokeTick;
+    use crate::{prelude::*, *};
+
+    fn dilated() {
+        let w = TickWaiter::<SmokeTick, _, u32>::us(1.millis(), NonInterval::new(), 1_000_000);
+        let w = DilatedWaiter::new(w, 2, 1);
+        let _ = w.start().timeout();
+    }
+
+    fn jittered() {
+        let w = TimedTickWaiter::<SmokeTick, _>::new(NonInterval::new(), 1_000_000);
+        let w = JitteredWaiter::new(w, 10.micros(), 1);
+        let _ = w.start(1.millis()).timeout();
+    }
+}
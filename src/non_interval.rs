@@ -5,7 +5,7 @@ use super::*;
 pub struct NonInterval {}
 
 impl NonInterval {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
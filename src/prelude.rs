@@ -1,2 +1,8 @@
-pub use crate::{Interval as _, TickInstant as _, TimedWaiter, Waiter, WaiterStatus};
+//! Traits for the blocking waiters.
+//!
+//! It contains [`Waiter`], [`WaiterMut`], [`TimedWaiter`], [`WaiterStatus`], and [`Interval`](crate::Interval) and
+//! [`TickInstant`](crate::TickInstant) for calling their methods, plus [`ExtU32`](fugit::ExtU32) for durations.
+//! For the async traits, see [`async_prelude`](crate::async_prelude) (needs the `async` feature).
+
+pub use crate::{Interval as _, TickInstant as _, TimedWaiter, Waiter, WaiterMut, WaiterStatus};
 pub use fugit::ExtU32 as _;
@@ -0,0 +1,247 @@
+use super::*;
+use core::marker::PhantomData;
+use fugit::MicrosDurationU32;
+
+/// Limit how often the inner status is polled.
+///
+/// If `timeout()` is called sooner than `min_gap` after the previous poll,
+/// it runs `interval` until the gap has passed, then polls the inner status.
+/// The first poll after `start()` or `restart()` isn't delayed.
+/// [`check()`](WaiterStatus::check) never runs `interval`, it returns `false`
+/// without polling the inner status if the gap hasn't passed.
+///
+/// It enforces a minimum gap between polls of the inner status, e.g. to bound how often
+/// an inner status querying a slow peripheral is polled. Measuring the gap reads `T` once
+/// per pass, on top of the inner status' own clock read, so `T` should be cheap.
+/// With a [`NonInterval`], `timeout()` busy-spins on `T` until the gap has passed.
+///
+/// The time since the last inner poll is accumulated at each pass and saturates,
+/// so like [`TickWaiterStatus`], it must be polled at least once every `u32::MAX` ticks.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, NonInterval, RateLimitedWaiter, StdInterval, StdWaiter};
+///
+/// let w = RateLimitedWaiter::<_, Instant, _>::new(
+///     StdWaiter::new(Duration::from_millis(10), NonInterval::new()),
+///     2.millis(),
+///     StdInterval::new(Duration::ZERO),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+/// let mut t = w.start();
+/// while !t.timeout() {}
+/// assert!(t.poll_count() <= 6);
+/// ```
+pub struct RateLimitedWaiter<W, T, I> {
+    waiter: W,
+    gap_tick: u32,
+    interval: I,
+    _t: PhantomData<T>,
+}
+
+impl<W, T, I> RateLimitedWaiter<W, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// - `waiter`: the inner waiter.
+    /// - `min_gap`: the minimum time between two polls of the inner status.
+    /// - `interval`: runs while waiting for the gap.
+    /// - `frequency`: the frequency of `T`.
+    pub fn new(waiter: W, min_gap: MicrosDurationU32, interval: I, frequency: u32) -> Self {
+        Self {
+            waiter,
+            gap_tick: us_to_ticks(min_gap.ticks() as u64, frequency),
+            interval,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<W, T, I> Waiter for RateLimitedWaiter<W, T, I>
+where
+    W: Waiter,
+    T: TickInstant,
+    I: Interval,
+{
+    fn start(&self) -> impl WaiterStatus {
+        RateLimitedStatus {
+            status: self.waiter.start(),
+            tick: T::now(),
+            since_poll: u32::MAX,
+            waiter: self,
+        }
+    }
+}
+
+impl<W, T, I> TimedWaiter for RateLimitedWaiter<W, T, I>
+where
+    W: TimedWaiter,
+    T: TickInstant,
+    I: Interval,
+{
+    fn start(&self, timeout: MicrosDurationU32) -> impl WaiterStatus {
+        RateLimitedStatus {
+            status: self.waiter.start(timeout),
+            tick: T::now(),
+            since_poll: u32::MAX,
+            waiter: self,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`RateLimitedWaiter`].
+pub struct RateLimitedStatus<'a, S, W, T, I> {
+    status: S,
+    tick: T,
+    since_poll: u32,
+    waiter: &'a RateLimitedWaiter<W, T, I>,
+}
+
+impl<'a, S, W, T, I> RateLimitedStatus<'a, S, W, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// Sample the clock once and tell whether the gap has passed since the last inner poll.
+    #[inline]
+    fn gap_passed(&mut self) -> bool {
+        let now = T::now();
+        self.since_poll = self.since_poll.saturating_add(now.tick_since(self.tick));
+        self.tick = now;
+        self.since_poll >= self.waiter.gap_tick
+    }
+}
+
+impl<'a, S, W, T, I> WaiterStatus for RateLimitedStatus<'a, S, W, T, I>
+where
+    S: WaiterStatus,
+    T: TickInstant,
+    I: Interval,
+{
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        while !self.gap_passed() {
+            self.waiter.interval.interval();
+        }
+        self.since_poll = 0;
+        self.status.timeout()
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        if !self.gap_passed() {
+            return false;
+        }
+        self.since_poll = 0;
+        self.status.check()
+    }
+
+    #[inline]
+    fn restart(&mut self) {
+        self.since_poll = u32::MAX;
+        self.status.restart();
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.status.poll_count()
+    }
+
+    /// Only the inner interval counts, pacing the polls doesn't.
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.status.last_slept()
+    }
+
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        self.status.is_reusable()
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.status.refresh();
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        self.status.elapsed()
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        self.status.remaining()
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        self.status.timeout_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use core::cell::Cell;
+    use fugit::ExtU32;
+
+    #[test]
+    fn paced() {
+        let paces = Cell::new(0);
+        let pace = || {
+            paces.set(paces.get() + 1);
+            MockInstant::advance(10);
+        };
+        let w = RateLimitedWaiter::<_, MockInstant, _>::new(
+            TickWaiter::<MockInstant, _, _>::us(
+                100.micros(),
+                NonInterval::new(),
+                MockInstant::FREQUENCY,
+            ),
+            30.micros(),
+            FnInterval::new(&pace),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        assert!(!t.timeout());
+        assert_eq!(paces.get(), 0);
+        assert!(!t.timeout());
+        assert_eq!(paces.get(), 3);
+        MockInstant::advance(20);
+        assert!(!t.timeout());
+        assert_eq!(paces.get(), 4);
+        assert!(!t.timeout());
+        assert_eq!(paces.get(), 7);
+        assert!(t.timeout());
+        assert_eq!(paces.get(), 10);
+        assert_eq!(t.poll_count(), 5);
+
+        t.restart();
+        assert!(!t.timeout());
+        assert_eq!(paces.get(), 10);
+    }
+
+    #[test]
+    fn check_never_paces() {
+        let w = RateLimitedWaiter::<_, MockInstant, _>::new(
+            TickWaiter::<MockInstant, _, _>::us(
+                100.micros(),
+                NonInterval::new(),
+                MockInstant::FREQUENCY,
+            ),
+            30.micros(),
+            FnInterval::new(|| panic!("check() must not pace")),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        assert!(!t.check());
+        assert!(!t.check());
+        assert_eq!(t.poll_count(), 1);
+        MockInstant::advance(30);
+        assert!(!t.check());
+        assert_eq!(t.poll_count(), 2);
+        MockInstant::advance(70);
+        assert!(t.check());
+    }
+}
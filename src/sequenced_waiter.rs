@@ -0,0 +1,265 @@
+use super::*;
+use core::marker::PhantomData;
+use fugit::MicrosDurationU32;
+
+/// [`Waiter`] implementation that goes through a sequence of phases.
+///
+/// It's useful for multi-phase protocols, e.g. 10 ms reset + 20 ms settle + 50 ms response.
+/// When the current phase expires, the status moves on to the next one.
+/// `timeout()` only returns `true` after the last phase expires,
+/// and then keeps returning `true` until `restart()` is called.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, NonInterval, SequencedWaiter};
+///
+/// let phases = [1.millis(), 2.millis()];
+/// let w = SequencedWaiter::<Instant, _>::new(
+///     &phases,
+///     NonInterval::new(),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+/// let mut t = w.start();
+/// assert_eq!(t.phase(), 0);
+/// while !t.timeout() {}
+/// assert_eq!(t.phase(), 2);
+/// ```
+pub struct SequencedWaiter<'a, T, I> {
+    phases: &'a [MicrosDurationU32],
+    frequency: u32,
+    interval: I,
+    _t: PhantomData<T>,
+}
+
+impl<'a, T, I> SequencedWaiter<'a, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// - `phases`: the timeout of each phase.
+    /// - `interval`: Before the last phase expires,
+    ///   this action will execute each time `timeout()` is called.
+    /// - `frequency`: the frequency of `T`.
+    pub fn new(phases: &'a [MicrosDurationU32], interval: I, frequency: u32) -> Self {
+        Self::try_new(phases, interval, frequency).unwrap()
+    }
+
+    /// The same as [`new()`](Self::new), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    pub fn try_new(
+        phases: &'a [MicrosDurationU32],
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Ok(Self {
+            phases,
+            frequency,
+            interval,
+            _t: PhantomData,
+        })
+    }
+
+    /// The timeout of `phase` in ticks, `0` past the last phase.
+    #[inline]
+    fn phase_tick(&self, phase: usize) -> u32 {
+        self.phases
+            .get(phase)
+            .map_or(0, |p| us_to_ticks(p.ticks() as u64, self.frequency))
+    }
+}
+
+impl<'a, T, I> Waiter for SequencedWaiter<'a, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> SequencedStatus<'_, 'a, T, I> {
+        SequencedStatus {
+            tick: T::now(),
+            elapsed_tick: 0,
+            phase: 0,
+            phase_tick: self.phase_tick(0),
+            poll_count: 0,
+            slept: false,
+            waiter: self,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`SequencedWaiter`].
+pub struct SequencedStatus<'b, 'a, T, I> {
+    tick: T,
+    elapsed_tick: u32,
+    phase: usize,
+    phase_tick: u32,
+    poll_count: u32,
+    slept: bool,
+    waiter: &'b SequencedWaiter<'a, T, I>,
+}
+
+impl<'b, 'a, T, I> SequencedStatus<'b, 'a, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    /// The index of the current phase.
+    /// It equals the number of phases after the last one expires.
+    #[inline(always)]
+    pub fn phase(&self) -> usize {
+        self.phase
+    }
+
+    fn done_us(&self) -> u32 {
+        self.waiter.phases[..self.phase]
+            .iter()
+            .fold(0u32, |sum, p| sum.saturating_add(p.ticks()))
+    }
+}
+
+impl<'b, 'a, T, I> WaiterStatus for SequencedStatus<'b, 'a, T, I>
+where
+    T: TickInstant,
+    I: Interval,
+{
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        let expired = self.check();
+        interval_unless(expired, &self.waiter.interval, &mut self.slept)
+    }
+
+    fn check(&mut self) -> bool {
+        self.refresh();
+        self.poll_count = self.poll_count.saturating_add(1);
+        while self.phase < self.waiter.phases.len() {
+            if self.elapsed_tick < self.phase_tick {
+                return false;
+            }
+            self.elapsed_tick -= self.phase_tick;
+            self.phase += 1;
+            self.phase_tick = self.waiter.phase_tick(self.phase);
+        }
+        true
+    }
+
+    #[inline]
+    fn restart(&mut self) {
+        self.tick = T::now();
+        self.elapsed_tick = 0;
+        self.phase = 0;
+        self.phase_tick = self.waiter.phase_tick(0);
+        self.poll_count = 0;
+        self.slept = false;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        let now = T::now();
+        self.elapsed_tick = self.elapsed_tick.add_u32(now.tick_since(self.tick));
+        self.tick = now;
+    }
+
+    /// Time elapsed in all phases.
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        let current = ticks_to_us(self.elapsed_tick as u64, self.waiter.frequency).ticks();
+        Some(MicrosDurationU32::from_ticks(
+            self.done_us().saturating_add(current),
+        ))
+    }
+
+    /// Time remaining until the last phase expires.
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        let total = self.timeout_duration()?;
+        Some(MicrosDurationU32::from_ticks(
+            total.ticks().saturating_sub(self.elapsed()?.ticks()),
+        ))
+    }
+
+    /// The sum of all phases.
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(MicrosDurationU32::from_ticks(
+            self.waiter
+                .phases
+                .iter()
+                .fold(0u32, |sum, p| sum.saturating_add(p.ticks())),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockInstant, assert_reuse_contract};
+    use fugit::ExtU32;
+
+    #[test]
+    fn phases() {
+        let phases = [10.micros(), 20.micros(), 50.micros()];
+        let w = SequencedWaiter::<MockInstant, _>::new(
+            &phases,
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        assert_eq!(t.timeout_duration(), Some(80.micros()));
+        MockInstant::advance(9);
+        assert!(!t.timeout());
+        assert_eq!(t.phase(), 0);
+        MockInstant::advance(1);
+        assert!(!t.timeout());
+        assert_eq!(t.phase(), 1);
+        MockInstant::advance(25);
+        assert!(!t.timeout());
+        assert_eq!(t.phase(), 2);
+        assert_eq!(t.elapsed(), Some(35.micros()));
+        assert_eq!(t.remaining(), Some(45.micros()));
+        MockInstant::advance(45);
+        assert!(t.timeout());
+        assert_eq!(t.phase(), 3);
+        assert!(t.timeout());
+
+        t.restart();
+        assert_eq!(t.phase(), 0);
+        // Skip several phases in one poll
+        MockInstant::advance(30);
+        assert!(!t.timeout());
+        assert_eq!(t.phase(), 2);
+    }
+
+    #[test]
+    fn is_reusable() {
+        let phases = [10.micros(), 20.micros()];
+        let w = SequencedWaiter::<MockInstant, _>::new(
+            &phases,
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        assert!(!t.is_reusable());
+        MockInstant::advance(30);
+        assert_reuse_contract(&mut t);
+    }
+
+    #[test]
+    fn invalid_frequency() {
+        let phases = [10.micros()];
+        let w = SequencedWaiter::<MockInstant, _>::try_new(&phases, NonInterval::new(), 0);
+        assert_eq!(w.err(), Some(TimeoutError::InvalidFrequency));
+    }
+}
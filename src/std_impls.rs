@@ -1,4 +1,5 @@
 use super::*;
+use core::cell::Cell;
 use std::{
     thread::{sleep, yield_now},
     time::{Duration, Instant},
@@ -33,39 +34,161 @@ impl<I: Interval> StdWaiter<I> {
     pub fn new(timeout: Duration, interval: I) -> Self {
         Self { timeout, interval }
     }
+
+    /// Create it with a frequency-independent [`Timeout`].
+    pub fn with_timeout(timeout: impl Into<Timeout>, interval: I) -> Self {
+        Self::new(timeout.into().into(), interval)
+    }
+}
+
+impl StdWaiter<NonInterval> {
+    /// Start a [`StdWaiterBuilder`], which validates the interval against `timeout`.
+    pub fn builder(timeout: Duration) -> StdWaiterBuilder<NonInterval> {
+        StdWaiterBuilder {
+            timeout,
+            interval: NonInterval::new(),
+            sleep: Duration::ZERO,
+        }
+    }
+}
+
+/// Builder of [`StdWaiter`] checking the interval isn't too long for the timeout.
+///
+/// An interval sleeping longer than half the timeout overshoots the timeout by up to
+/// a whole interval and only polls once or twice, so [`build()`](Self::build) rejects it.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use waiter_trait::{StdInterval, StdWaiter, TimeoutError};
+///
+/// let w = StdWaiter::builder(Duration::from_millis(100))
+///     .interval(StdInterval::new(Duration::from_millis(10)))
+///     .build();
+/// assert!(w.is_ok());
+///
+/// let w = StdWaiter::builder(Duration::from_millis(100))
+///     .interval(StdInterval::new(Duration::from_millis(80)))
+///     .build();
+/// assert_eq!(w.err(), Some(TimeoutError::IntervalTooLong));
+/// ```
+pub struct StdWaiterBuilder<I> {
+    timeout: Duration,
+    interval: I,
+    sleep: Duration,
+}
+
+impl<I: Interval> StdWaiterBuilder<I> {
+    /// Use `interval` instead of [`NonInterval`].
+    pub fn interval(self, interval: StdInterval) -> StdWaiterBuilder<StdInterval> {
+        StdWaiterBuilder {
+            timeout: self.timeout,
+            sleep: interval.duration(),
+            interval,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// - [`TimeoutError::IntervalTooLong`]: the interval sleeps longer than half the timeout.
+    pub fn build(self) -> Result<StdWaiter<I>, TimeoutError> {
+        if self.sleep > self.timeout / 2 {
+            return Err(TimeoutError::IntervalTooLong);
+        }
+        Ok(StdWaiter::new(self.timeout, self.interval))
+    }
+}
+
+/// A 1-second timeout with [`NonInterval`].
+impl Default for StdWaiter<NonInterval> {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), NonInterval::new())
+    }
 }
 
 impl<I: Interval> Waiter for StdWaiter<I> {
     #[inline]
     fn start(&self) -> impl WaiterStatus {
+        let now = Instant::now();
         StdWaiterStatus {
-            start_time: Instant::now(),
+            start_time: now,
+            now,
+            poll_count: 0,
+            slept: false,
             waiter: self,
         }
     }
 }
 
 /// [`WaiterStatus`] implementation for `std`.
+///
+/// It isn't [reusable](WaiterStatus::is_reusable): once the time limit expires,
+/// `timeout()` keeps returning `true` until `restart()` is called.
 pub struct StdWaiterStatus<'a, I> {
     start_time: Instant,
+    now: Instant,
+    poll_count: u32,
+    slept: bool,
     waiter: &'a StdWaiter<I>,
 }
 
 impl<'a, I: Interval> WaiterStatus for StdWaiterStatus<'a, I> {
     #[inline]
     fn timeout(&mut self) -> bool {
-        if self.start_time.elapsed() >= self.waiter.timeout {
-            true
-        } else {
-            self.waiter.interval.interval();
-            false
-        }
+        let expired = self.check();
+        interval_unless(expired, &self.waiter.interval, &mut self.slept)
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.refresh();
+        self.poll_count = self.poll_count.saturating_add(1);
+        self.now - self.start_time >= self.waiter.timeout
     }
 
     #[inline(always)]
     fn restart(&mut self) {
         self.start_time = Instant::now();
+        self.now = self.start_time;
+        self.poll_count = 0;
+        self.slept = false;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    #[inline(always)]
+    fn refresh(&mut self) {
+        self.now = Instant::now();
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        Some(duration_to_us(self.now - self.start_time))
     }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        Some(duration_to_us(
+            self.waiter
+                .timeout
+                .saturating_sub(self.now - self.start_time),
+        ))
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(duration_to_us(self.waiter.timeout))
+    }
+}
+
+fn duration_to_us(d: Duration) -> MicrosDurationU32 {
+    MicrosDurationU32::from_ticks(d.as_micros().min(u32::MAX as u128) as u32)
 }
 
 impl TickInstant for Instant {
@@ -93,6 +216,12 @@ impl StdInterval {
     pub fn new(duration: Duration) -> Self {
         Self { duration }
     }
+
+    /// The sleep duration given to [`new()`](Self::new).
+    #[inline(always)]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
 }
 
 impl Interval for StdInterval {
@@ -105,6 +234,57 @@ impl Interval for StdInterval {
     }
 }
 
+/// [`Interval`] implementation for `std` that spins then blocks.
+///
+/// The first `yields` calls of `interval()` call `yield_now()` for low latency
+/// when the event is imminent, the following calls `sleep(duration)` to save CPU
+/// when the wait is long.
+///
+/// The count is kept in the interval, not in the [`WaiterStatus`],
+/// so call [`reset()`](Self::reset) before reusing it for another wait.
+/// A clone starts with the count reset.
+pub struct EscalatingInterval {
+    yields: u32,
+    duration: Duration,
+    count: Cell<u32>,
+}
+
+impl EscalatingInterval {
+    /// - `yields`: the number of `interval()` calls to `yield_now()`.
+    /// - `duration`: the `sleep` duration after that.
+    pub fn new(yields: u32, duration: Duration) -> Self {
+        Self {
+            yields,
+            duration,
+            count: Cell::new(0),
+        }
+    }
+
+    /// Go back to `yield_now()`.
+    pub fn reset(&self) {
+        self.count.set(0);
+    }
+}
+
+impl Clone for EscalatingInterval {
+    fn clone(&self) -> Self {
+        Self::new(self.yields, self.duration)
+    }
+}
+
+impl Interval for EscalatingInterval {
+    #[inline]
+    fn interval(&self) {
+        let count = self.count.get();
+        if count < self.yields {
+            self.count.set(count + 1);
+            yield_now();
+        } else {
+            sleep(self.duration);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,4 +316,85 @@ mod tests {
         assert!(t.timeout());
         assert!(t.timeout());
     }
+
+    #[test]
+    fn default() {
+        let w = StdWaiter::default();
+        assert_eq!(w.timeout, Duration::from_secs(1));
+        let t = w.start();
+        assert_eq!(
+            t.timeout_duration(),
+            Some(MicrosDurationU32::from_ticks(1_000_000))
+        );
+    }
+
+    #[test]
+    fn builder() {
+        let build = |sleep| {
+            StdWaiter::builder(Duration::from_millis(100))
+                .interval(StdInterval::new(Duration::from_millis(sleep)))
+                .build()
+                .err()
+        };
+        assert_eq!(build(0), None);
+        assert_eq!(build(50), None);
+        assert_eq!(build(51), Some(TimeoutError::IntervalTooLong));
+        assert!(StdWaiter::builder(Duration::ZERO).build().is_ok());
+    }
+
+    #[test]
+    fn is_reusable() {
+        let w = StdWaiter::new(Duration::from_millis(1), NonInterval::new());
+        let mut t = w.start();
+        assert!(!t.is_reusable());
+        sleep(Duration::from_millis(1));
+        crate::mock::assert_reuse_contract(&mut t);
+    }
+
+    #[test]
+    fn escalating_interval() {
+        let i = EscalatingInterval::new(3, Duration::from_millis(1));
+        // The count only goes up on the calls that yield.
+        for n in 1..=3 {
+            i.interval();
+            assert_eq!(i.count.get(), n);
+        }
+        for _ in 0..2 {
+            i.interval();
+            assert_eq!(i.count.get(), 3);
+        }
+
+        let c = i.clone();
+        assert_eq!(c.count.get(), 0);
+        c.interval();
+        assert_eq!(c.count.get(), 1);
+        i.reset();
+        assert_eq!(i.count.get(), 0);
+        i.interval();
+        assert_eq!(i.count.get(), 1);
+    }
+
+    #[test]
+    fn poll_count() {
+        let w = StdWaiter::new(
+            Duration::from_millis(50),
+            StdInterval::new(Duration::from_millis(20)),
+        );
+        let mut t = w.start();
+        assert_eq!(t.timeout_duration().unwrap().ticks(), 50_000);
+        assert_eq!(t.poll_count(), 0);
+        let mut calls = 0;
+        loop {
+            calls += 1;
+            if t.timeout() {
+                break;
+            }
+        }
+        assert_eq!(t.poll_count(), calls);
+        assert!(t.timeout());
+        assert_eq!(t.poll_count(), calls + 1);
+
+        t.restart();
+        assert_eq!(t.poll_count(), 0);
+    }
 }
@@ -0,0 +1,81 @@
+use super::*;
+use core::cell::RefCell;
+use cortex_m::delay::Delay;
+
+/// [`Interval`] implementation sleeping a precise short time with a SysTick delay.
+/// Needs the `cortex-m` feature enabled.
+///
+/// It calls [`delay_us()`](DelayNs::delay_us) between polls, so the gaps are precise
+/// without a coarse busy-spin on the tick counter. SysTick must be configured first,
+/// usually by creating a [`cortex_m::delay::Delay`] with the core clock frequency.
+/// Any other [`DelayNs`] works too.
+///
+/// [`Interval`] requires `Clone`, but the delay owns SysTick and can't be cloned.
+/// So it holds a shared `&RefCell` and every clone uses the same delay.
+/// The delay can't be borrowed elsewhere while `interval()` runs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use core::cell::RefCell;
+/// use cortex_m::delay::Delay;
+/// use waiter_trait::{prelude::*, SysTickInterval, TimedTickWaiter, WrappingTick};
+///
+/// let cp = cortex_m::Peripherals::take().unwrap();
+/// let delay = RefCell::new(Delay::new(cp.SYST, 48_000_000));
+///
+/// let w = TimedTickWaiter::<WrappingTick, _>::new(SysTickInterval::new(&delay, 10), 48_000_000);
+/// let mut t = w.start(1.millis());
+/// while !t.timeout() {}
+/// ```
+pub struct SysTickInterval<'a, D = Delay> {
+    delay: &'a RefCell<D>,
+    us: u32,
+}
+
+impl<'a, D: DelayNs> SysTickInterval<'a, D> {
+    /// - `delay`: a configured delay.
+    /// - `us`: the sleep time between polls in microseconds.
+    pub fn new(delay: &'a RefCell<D>, us: u32) -> Self {
+        Self { delay, us }
+    }
+}
+
+impl<D> Clone for SysTickInterval<'_, D> {
+    fn clone(&self) -> Self {
+        Self {
+            delay: self.delay,
+            us: self.us,
+        }
+    }
+}
+
+impl<D: DelayNs> Interval for SysTickInterval<'_, D> {
+    #[inline]
+    fn interval(&self) {
+        self.delay.borrow_mut().delay_us(self.us);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockDelay, MockInstant};
+    use fugit::ExtU32;
+
+    #[test]
+    fn sleeps_between_polls() {
+        let delay = RefCell::new(MockDelay);
+        let w = TickWaiter::<MockInstant, _, _>::us(
+            100.micros(),
+            SysTickInterval::new(&delay, 30),
+            MockInstant::FREQUENCY,
+        );
+        let mut t = w.start();
+        let mut polls = 0;
+        while !t.timeout() {
+            polls += 1;
+        }
+        assert_eq!(polls, 4);
+    }
+}
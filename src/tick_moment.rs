@@ -0,0 +1,76 @@
+use super::*;
+use fugit::MicrosDurationU32;
+
+/// A monotonic instant built on a [`TickInstant`], like `std::time::Instant` for `no_std`.
+///
+/// It's based on [`TickInstant::tick_since`], so the result is only correct if
+/// less than one full counter period (`u32::MAX` ticks) has elapsed since the moment was taken.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::TickMoment;
+///
+/// let frequency = Duration::from_secs(1).as_nanos() as u32;
+/// let a = TickMoment::<Instant>::now(frequency);
+/// std::thread::sleep(Duration::from_millis(10));
+/// let b = TickMoment::<Instant>::now(frequency);
+/// assert!(b.duration_since(a).to_millis() >= 10);
+/// assert!(a.elapsed() >= b.duration_since(a));
+/// ```
+#[derive(Clone, Copy)]
+pub struct TickMoment<T> {
+    tick: T,
+    frequency: u32,
+}
+
+impl<T> TickMoment<T>
+where
+    T: TickInstant,
+{
+    /// - `frequency`: the frequency of `T`.
+    pub fn now(frequency: u32) -> Self {
+        Self {
+            tick: T::now(),
+            frequency,
+        }
+    }
+
+    /// The captured tick.
+    pub fn tick(self) -> T {
+        self.tick
+    }
+
+    /// Returns the amount of time elapsed from another moment to this one.
+    pub fn duration_since(self, earlier: Self) -> MicrosDurationU32 {
+        ticks_to_us(self.tick.tick_since(earlier.tick) as u64, self.frequency)
+    }
+
+    /// Returns the amount of time elapsed since this moment.
+    pub fn elapsed(self) -> MicrosDurationU32 {
+        ticks_to_us(self.tick.tick_elapsed() as u64, self.frequency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+
+    #[test]
+    fn moments() {
+        let a = TickMoment::<MockInstant>::now(MockInstant::FREQUENCY);
+        MockInstant::advance(1_500);
+        let b = TickMoment::<MockInstant>::now(MockInstant::FREQUENCY);
+        assert_eq!(b.duration_since(a).ticks(), 1_500);
+        assert_eq!(a.elapsed().ticks(), 1_500);
+        MockInstant::advance(500);
+        assert_eq!(a.elapsed().ticks(), 2_000);
+        assert_eq!(b.elapsed().ticks(), 500);
+
+        let a = TickMoment::<MockInstant>::now(8 * MockInstant::FREQUENCY);
+        MockInstant::advance(8_000);
+        assert_eq!(a.elapsed().ticks(), 1_000);
+    }
+}
@@ -31,12 +31,110 @@ use fugit::{
 /// assert!(!t.timeout());
 /// assert!(t.timeout());
 /// ```
+///
+/// # Resolution
+///
+/// The timeout is rounded up to whole ticks, see [`resolution()`](Self::resolution).
+/// So a nonzero timeout shorter than one tick always takes exactly one tick:
+/// it expires on the first poll after the counter advances. A zero timeout expires
+/// on the first poll. Compare [`WaiterStatus::timeout_duration()`] with the requested
+/// timeout to see how much it was rounded.
+///
+/// # RISC-V `mtime`
+///
+/// `mtime` is a 64-bit counter, often running at a frequency that isn't a multiple of 1 MHz,
+/// such as 32768 Hz. Any nonzero `frequency` is supported, the timeout is rounded up to
+/// whole ticks. Wrap the counter in a [`TickInstant`] and use `u64` ticks for long timeouts:
+///
+/// ```
+/// use fugit::MicrosDurationU64;
+/// use waiter_trait::{prelude::*, NonInterval, TickInstant, TickWaiter};
+///
+/// #[derive(Clone, Copy)]
+/// struct Mtime(u64);
+///
+/// impl TickInstant for Mtime {
+///     fn now() -> Self {
+///         // Read the `mtime` register here.
+///         Self(0)
+///     }
+///
+///     fn tick_since(self, earlier: Self) -> u32 {
+///         self.0.wrapping_sub(earlier.0) as u32
+///     }
+/// }
+///
+/// let w = TickWaiter::<Mtime, _, u64>::us_u64(
+///     MicrosDurationU64::secs(3 * 3600),
+///     NonInterval::new(),
+///     32_768,
+/// );
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// ```
+///
+/// `tick_since()` returns `u32`, so `timeout()` must be called at least once every
+/// `u32::MAX` ticks, about 36 hours at 32768 Hz.
 pub struct TickWaiter<T, I, N> {
-    timeout_tick: N,
-    interval: I,
+    pub(crate) timeout_tick: N,
+    pub(crate) frequency: u32,
+    pub(crate) interval: I,
     _t: PhantomData<T>,
 }
 
+impl<T, I, N> TickWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    /// `timeout * ticks_per_unit / div`, rounded up.
+    pub(crate) fn try_with(
+        timeout: u64,
+        ticks_per_unit: u32,
+        div: u64,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        let timeout_tick = (timeout as u128 * ticks_per_unit as u128).div_ceil(div as u128);
+        Ok(Self {
+            timeout_tick: u64::try_from(timeout_tick)
+                .ok()
+                .and_then(N::from_u64)
+                .ok_or(TimeoutError::Overflow)?,
+            frequency,
+            interval,
+            _t: PhantomData,
+        })
+    }
+
+    /// Create it with a frequency-independent [`Timeout`].
+    pub fn with_timeout(timeout: impl Into<Timeout>, interval: I, frequency: u32) -> Self {
+        Self::try_with_timeout(timeout, interval, frequency).unwrap()
+    }
+
+    /// The same as [`with_timeout()`](Self::with_timeout), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `N` ticks.
+    pub fn try_with_timeout(
+        timeout: impl Into<Timeout>,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(
+            timeout.into().micros().ticks(),
+            frequency,
+            1_000_000,
+            interval,
+            frequency,
+        )
+    }
+}
+
 impl<T, I> TickWaiter<T, I, u32>
 where
     T: TickInstant,
@@ -44,35 +142,124 @@ where
 {
     /// If you want to wait longer, lower the frequency or/and use other initialization functions.
     pub fn ns(timeout: NanosDurationU32, interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000_000, 0);
-        let timeout_tick = (timeout.ticks() as u64)
-            .checked_mul((frequency / 1_000_000) as u64)
-            .unwrap()
-            .div_ceil(1_000);
-        assert!(timeout_tick <= u32::MAX as u64);
+        Self::try_ns(timeout, interval, frequency).unwrap()
+    }
+
+    pub fn us(timeout: MicrosDurationU32, interval: I, frequency: u32) -> Self {
+        Self::try_us(timeout, interval, frequency).unwrap()
+    }
+
+    pub fn ms(timeout: MillisDurationU32, interval: I, frequency: u32) -> Self {
+        Self::try_ms(timeout, interval, frequency).unwrap()
+    }
+
+    /// The same as [`us()`](Self::us), but it's a `const fn`.
+    ///
+    /// With constant arguments, the conversion is done at compile time,
+    /// so it can be used to initialize a `const` or `static` waiter.
+    /// A `const fn` can't call trait methods, so the timeout is a plain `u32`
+    /// in microseconds and `interval` must be constructed in a `const` context too.
+    /// Like `us()`, it accepts any frequency and rounds the timeout up to whole ticks.
+    ///
+    /// # Panics
+    ///
+    /// If `frequency` is `0` or the timeout doesn't fit in `u32` ticks.
+    /// In a `const` context, it's a compile error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use waiter_trait::{NonInterval, TickWaiter};
+    ///
+    /// static WAITER: TickWaiter<Instant, NonInterval, u32> =
+    ///     TickWaiter::us_const(100, NonInterval::new(), 1_000_000_000);
+    /// ```
+    pub const fn us_const(timeout_us: u32, interval: I, frequency: u32) -> Self {
+        Self::const_with(timeout_us, 1_000_000, interval, frequency)
+    }
+
+    /// The same as [`ms()`](Self::ms), but it's a `const fn`.
+    ///
+    /// See [`us_const()`](Self::us_const) for the constraints.
+    pub const fn ms_const(timeout_ms: u32, interval: I, frequency: u32) -> Self {
+        Self::const_with(timeout_ms, 1_000, interval, frequency)
+    }
+
+    /// `timeout * frequency / div`, rounded up, the same as [`try_with()`](Self::try_with).
+    const fn const_with(timeout: u32, div: u32, interval: I, frequency: u32) -> Self {
+        assert!(frequency > 0);
+        let timeout_tick = (timeout as u128 * frequency as u128).div_ceil(div as u128);
+        assert!(timeout_tick <= u32::MAX as u128);
         Self {
             timeout_tick: timeout_tick as u32,
+            frequency,
             interval,
             _t: PhantomData,
         }
     }
 
-    pub fn us(timeout: MicrosDurationU32, interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000_000, 0);
-        Self {
-            timeout_tick: timeout.ticks().checked_mul(frequency / 1_000_000).unwrap(),
+    /// The same as [`ns()`](Self::ns), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `u32` ticks.
+    pub fn try_ns(
+        timeout: NanosDurationU32,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(
+            timeout.ticks() as u64,
+            frequency,
+            1_000_000_000,
             interval,
-            _t: PhantomData,
-        }
+            frequency,
+        )
     }
 
-    pub fn ms(timeout: MillisDurationU32, interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000, 0);
-        Self {
-            timeout_tick: timeout.ticks().checked_mul(frequency / 1_000).unwrap(),
+    /// The same as [`us()`](Self::us), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `u32` ticks.
+    pub fn try_us(
+        timeout: MicrosDurationU32,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(
+            timeout.ticks() as u64,
+            frequency,
+            1_000_000,
             interval,
-            _t: PhantomData,
-        }
+            frequency,
+        )
+    }
+
+    /// The same as [`ms()`](Self::ms), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `u32` ticks.
+    pub fn try_ms(
+        timeout: MillisDurationU32,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(
+            timeout.ticks() as u64,
+            frequency,
+            1_000,
+            interval,
+            frequency,
+        )
     }
 }
 
@@ -82,28 +269,55 @@ where
     I: Interval,
 {
     pub fn ns_u64(timeout: NanosDurationU64, interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000_000, 0);
-        Self {
-            timeout_tick: timeout
-                .ticks()
-                .checked_mul((frequency / 1_000_000) as u64)
-                .unwrap()
-                .div_ceil(1_000),
-            interval,
-            _t: PhantomData,
-        }
+        Self::try_ns_u64(timeout, interval, frequency).unwrap()
     }
 
     pub fn us_u64(timeout: MicrosDurationU64, interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000_000, 0);
-        Self {
-            timeout_tick: timeout
-                .ticks()
-                .checked_mul((frequency / 1_000_000) as u64)
-                .unwrap(),
+        Self::try_us_u64(timeout, interval, frequency).unwrap()
+    }
+
+    /// The same as [`ns_u64()`](Self::ns_u64), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `u64` ticks.
+    pub fn try_ns_u64(
+        timeout: NanosDurationU64,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(
+            timeout.ticks(),
+            frequency,
+            1_000_000_000,
             interval,
-            _t: PhantomData,
-        }
+            frequency,
+        )
+    }
+
+    /// The same as [`us_u64()`](Self::us_u64), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0`.
+    /// - [`TimeoutError::Overflow`]: the timeout doesn't fit in `u64` ticks.
+    pub fn try_us_u64(
+        timeout: MicrosDurationU64,
+        interval: I,
+        frequency: u32,
+    ) -> Result<Self, TimeoutError> {
+        check_frequency(frequency)?;
+        Self::try_with(timeout.ticks(), frequency, 1_000_000, interval, frequency)
+    }
+}
+
+pub(crate) fn check_frequency(frequency: u32) -> Result<(), TimeoutError> {
+    if frequency == 0 {
+        Err(TimeoutError::InvalidFrequency)
+    } else {
+        Ok(())
     }
 }
 
@@ -113,39 +327,243 @@ where
     T: TickInstant,
     I: Interval,
 {
-    fn start(&self) -> impl WaiterStatus {
-        TickWaiterStatus::<T, I, N> {
-            tick: T::now(),
-            elapsed_tick: N::ZERO,
-            waiter: self,
-        }
+    #[allow(refining_impl_trait)]
+    fn start(&self) -> TickWaiterStatus<T, &I, N> {
+        TickWaiterStatus::new(self.timeout_tick, self.frequency, &self.interval)
     }
 }
 
-pub struct TickWaiterStatus<'a, T: TickInstant, I: Interval, N: Num> {
+impl<T, I, N> TickWaiter<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    /// Start waiting in one-shot mode.
+    ///
+    /// Unlike [`start()`](Waiter::start), once `timeout()` returns `true`,
+    /// it keeps returning `true` until `restart()` is called.
+    pub fn start_oneshot(&self) -> TickWaiterStatus<T, &I, N> {
+        TickWaiterStatus::new(self.timeout_tick, self.frequency, &self.interval).oneshot()
+    }
+
+    /// Start waiting with a status counting the remaining ticks down.
+    ///
+    /// It expires at the same time as [`start_oneshot()`](Self::start_oneshot),
+    /// see [`TickCountdownStatus`] for the tradeoffs.
+    pub fn start_countdown(&self) -> TickCountdownStatus<T, &I, N> {
+        TickCountdownStatus::new(self.timeout_tick, self.frequency, &self.interval)
+    }
+
+    /// The duration of one tick, rounded up to whole nanoseconds.
+    ///
+    /// Timeouts are rounded up to a multiple of it.
+    pub fn resolution(&self) -> NanosDurationU32 {
+        NanosDurationU32::from_ticks(1_000_000_000u32.div_ceil(self.frequency))
+    }
+
+    /// Returns a [`TickDelay`] with the same frequency.
+    ///
+    /// The interval isn't carried over, [`TickDelay`] just spins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use waiter_trait::{prelude::*, DelayNs, NonInterval, TickWaiter};
+    ///
+    /// let w = TickWaiter::<Instant, _, _>::ms(
+    ///     10.millis(),
+    ///     NonInterval::new(),
+    ///     Duration::from_secs(1).as_nanos() as u32,
+    /// );
+    /// let mut d = w.delay();
+    /// let t = Instant::now();
+    /// d.delay_ms(1);
+    /// assert!(t.elapsed() >= Duration::from_millis(1));
+    /// ```
+    pub fn delay(&self) -> TickDelay<T> {
+        TickDelay::new(self.frequency)
+    }
+}
+
+/// [`WaiterStatus`] implementation shared by [`TickWaiter`] and [`TimedTickWaiter`].
+///
+/// It works in one of two modes:
+/// - Periodic, returned by `start()`: when the time limit expires, the timeout is subtracted
+///   from the elapsed time, so it re-arms itself and can be reused without calling `restart()`.
+///   If the elapsed time has saturated at `N::MAX`, such as after a clock jump,
+///   it's reset to zero instead, so a single glitch doesn't cause a burst of expiries.
+/// - One-shot, returned by `start_oneshot()`: once the time limit expires,
+///   `timeout()` keeps returning `true` until `restart()` is called.
+///   Once latched, polls return immediately without reading the clock or running the interval.
+///
+/// The instant sampled by `timeout()`, `restart()` and `refresh()` is cached,
+/// so `elapsed()` and `remaining()` never read the clock.
+pub struct TickWaiterStatus<T, I, N> {
     tick: T,
     elapsed_tick: N,
-    waiter: &'a TickWaiter<T, I, N>,
+    timeout_tick: N,
+    frequency: u32,
+    poll_count: u32,
+    slept: bool,
+    oneshot: bool,
+    expired_at: Option<T>,
+    interval: I,
+}
+
+impl<T, I, N> TickWaiterStatus<T, I, N>
+where
+    N: Num,
+    T: TickInstant,
+    I: Interval,
+{
+    pub(crate) fn new(timeout_tick: N, frequency: u32, interval: I) -> Self {
+        Self {
+            tick: T::now(),
+            elapsed_tick: N::ZERO,
+            timeout_tick,
+            frequency,
+            poll_count: 0,
+            slept: false,
+            oneshot: false,
+            expired_at: None,
+            interval,
+        }
+    }
+
+    pub(crate) fn oneshot(mut self) -> Self {
+        self.oneshot = true;
+        self
+    }
+
+    /// The deadline in ticks.
+    ///
+    /// It's counted from `start()` or `restart()`,
+    /// or from the last expiry in periodic mode.
+    #[inline(always)]
+    pub fn deadline_ticks(&self) -> N {
+        self.timeout_tick
+    }
+
+    /// Move the deadline later by `ticks`, saturating at `N::MAX`.
+    ///
+    /// In periodic mode, it also applies to the following periods.
+    /// In one-shot mode, it can't undo an expiry that has already been latched.
+    #[inline]
+    pub fn extend(&mut self, ticks: u32) {
+        self.timeout_tick = self.timeout_tick.add_u32(ticks);
+    }
+
+    /// Set the deadline to `deadline_ticks`, counted the same way as
+    /// [`deadline_ticks()`](Self::deadline_ticks).
+    ///
+    /// If the deadline has already passed, the next poll expires immediately.
+    /// In periodic mode, it also applies to the following periods.
+    /// In one-shot mode, it can't undo an expiry that has already been latched.
+    #[inline]
+    pub fn extend_to(&mut self, deadline_ticks: N) {
+        self.timeout_tick = deadline_ticks;
+    }
+
+    /// Exclude `ticks` known to be spent elsewhere, e.g. in a long critical section,
+    /// from the elapsed time.
+    ///
+    /// It can't take back more than has elapsed since the last expiry, `restart()`
+    /// or `start()`: the elapsed time saturates at zero.
+    /// It applies to the time elapsed at the last sample, so call it after polling.
+    /// In one-shot mode, it can't undo an expiry that has already been latched.
+    #[inline]
+    pub fn discount(&mut self, ticks: u32) {
+        let ticks = N::from_u64(ticks as u64).unwrap_or(N::MAX);
+        if ticks >= self.elapsed_tick {
+            self.elapsed_tick = N::ZERO;
+        } else {
+            self.elapsed_tick -= ticks;
+        }
+    }
+
+    /// The instant when the first expiry was detected since `start()` or `restart()`.
+    ///
+    /// It's the time `timeout()` returned `true`, which lags the real deadline
+    /// by however long it took to poll again.
+    #[inline(always)]
+    pub fn expired_at(&self) -> Option<T> {
+        self.expired_at
+    }
+
+    /// Start a child status in one-shot mode, which can't outlive this one.
+    ///
+    /// The child's timeout is `child_timeout` clamped to the remaining time of this status
+    /// at the last sample, and it counts from that sample too. So the child expires
+    /// no later than this status would.
+    pub fn fork(&self, child_timeout: MicrosDurationU32) -> Self {
+        let remaining_tick = N::from_u64(
+            self.timeout_tick.as_u64() - self.elapsed_tick.as_u64().min(self.timeout_tick.as_u64()),
+        )
+        .unwrap_or(N::MAX);
+        let child_tick: N = us_to_ticks(child_timeout.ticks() as u64, self.frequency);
+        Self {
+            tick: self.tick,
+            elapsed_tick: N::ZERO,
+            timeout_tick: child_tick.min(remaining_tick),
+            frequency: self.frequency,
+            poll_count: 0,
+            slept: false,
+            oneshot: true,
+            expired_at: None,
+            interval: self.interval.clone(),
+        }
+    }
+
+    /// Restart with a new timeout in one call.
+    ///
+    /// It samples the clock, clears the elapsed time and sets the new timeout together,
+    /// so the old timeout can't expire in between.
+    /// The timeout saturates at `N::MAX` ticks.
+    pub fn reset_and_extend(&mut self, new_timeout: MicrosDurationU32) {
+        self.timeout_tick = us_to_ticks(new_timeout.ticks() as u64, self.frequency);
+        self.restart();
+    }
 }
 
-impl<'a, T, I, N> WaiterStatus for TickWaiterStatus<'a, T, I, N>
+impl<T, I, N> WaiterStatus for TickWaiterStatus<T, I, N>
 where
     N: Num,
     T: TickInstant,
     I: Interval,
 {
-    /// Can be reused without calling `restart()`.
+    /// Can be reused without calling `restart()` in periodic mode.
     #[inline]
     fn timeout(&mut self) -> bool {
-        let now = T::now();
-        self.elapsed_tick = self.elapsed_tick.add_u32(now.tick_since(self.tick));
-        self.tick = now;
+        let expired = self.check();
+        interval_unless(expired, &self.interval, &mut self.slept)
+    }
 
-        if self.elapsed_tick >= self.waiter.timeout_tick {
-            self.elapsed_tick -= self.waiter.timeout_tick;
+    #[inline]
+    fn check(&mut self) -> bool {
+        if self.oneshot && self.expired_at.is_some() {
+            self.poll_count = self.poll_count.saturating_add(1);
+            return true;
+        }
+        self.refresh();
+        self.poll_count = self.poll_count.saturating_add(1);
+
+        if self.elapsed_tick >= self.timeout_tick {
+            if self.expired_at.is_none() {
+                self.expired_at = Some(self.tick);
+            }
+            if self.oneshot {
+                // Keep expired until `restart()`.
+            } else if self.elapsed_tick == N::MAX {
+                // The elapsed time saturated, e.g. the clock jumped.
+                // The leftover is unknown, start the next period from now.
+                self.elapsed_tick = N::ZERO;
+            } else {
+                self.elapsed_tick -= self.timeout_tick;
+            }
             true
         } else {
-            self.waiter.interval.interval();
             false
         }
     }
@@ -154,24 +572,645 @@ where
     fn restart(&mut self) {
         self.tick = T::now();
         self.elapsed_tick = N::ZERO;
+        self.poll_count = 0;
+        self.slept = false;
+        self.expired_at = None;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    /// `true` in periodic mode, `false` in one-shot mode.
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        !self.oneshot
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        let now = T::now();
+        self.elapsed_tick = self.elapsed_tick.add_u32(now.tick_since(self.tick));
+        self.tick = now;
+    }
+
+    /// Time elapsed in the current period, since the status can be reused.
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.elapsed_tick.as_u64(), self.frequency))
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        let remaining_tick =
+            self.timeout_tick.as_u64() - self.elapsed_tick.as_u64().min(self.timeout_tick.as_u64());
+        Some(ticks_to_us(remaining_tick, self.frequency))
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.timeout_tick.as_u64(), self.frequency))
+    }
+}
+
+/// The longest timeout that fits in `N` ticks at `frequency`.
+///
+/// A waiter counting `N` ticks can't be constructed with a longer timeout,
+/// it needs a wider `N`. It saturates at `u64::MAX` microseconds.
+///
+/// # Panics
+///
+/// If `frequency` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use waiter_trait::max_timeout;
+///
+/// assert_eq!(max_timeout::<u32>(1_000_000).ticks(), u32::MAX as u64);
+/// ```
+pub fn max_timeout<N: Num>(frequency: u32) -> MicrosDurationU64 {
+    assert!(frequency > 0);
+    let us = N::MAX.as_u64() as u128 * 1_000_000 / frequency as u128;
+    MicrosDurationU64::from_ticks(us.min(u64::MAX as u128) as u64)
+}
+
+/// The tail of `timeout()` after `check()`: run `interval` unless `expired`,
+/// and record whether it ran in `slept`. Returns `expired`.
+#[inline]
+pub(crate) fn interval_unless(expired: bool, interval: &impl Interval, slept: &mut bool) -> bool {
+    if !expired {
+        interval.interval();
     }
+    *slept = !expired;
+    expired
+}
+
+/// Convert microseconds to ticks, rounded up and saturating at `N::MAX`.
+pub(crate) fn us_to_ticks<N: Num>(us: u64, frequency: u32) -> N {
+    let ticks = (us as u128 * frequency as u128).div_ceil(1_000_000);
+    u64::try_from(ticks)
+        .ok()
+        .and_then(N::from_u64)
+        .unwrap_or(N::MAX)
+}
+
+/// Convert ticks to a duration in microseconds, saturating at `u32::MAX` microseconds.
+pub(crate) fn ticks_to_us(ticks: u64, frequency: u32) -> MicrosDurationU32 {
+    let us = ticks as u128 * 1_000_000 / frequency as u128;
+    MicrosDurationU32::from_ticks(us.min(u32::MAX as u128) as u32)
 }
 
 pub trait Num: Sized + Copy + core::cmp::Ord + core::ops::SubAssign {
     const ZERO: Self;
+    const MAX: Self;
     fn add_u32(self, v: u32) -> Self;
+    fn as_u64(self) -> u64;
+    fn from_u64(v: u64) -> Option<Self>;
 }
 
 impl Num for u32 {
     const ZERO: Self = 0;
+    const MAX: Self = u32::MAX;
     fn add_u32(self, v: u32) -> Self {
         self.saturating_add(v)
     }
+    fn as_u64(self) -> u64 {
+        self as u64
+    }
+    fn from_u64(v: u64) -> Option<Self> {
+        v.try_into().ok()
+    }
 }
 
 impl Num for u64 {
     const ZERO: Self = 0u64;
+    const MAX: Self = u64::MAX;
     fn add_u32(self, v: u32) -> Self {
         self.saturating_add(v as u64)
     }
+    fn as_u64(self) -> u64 {
+        self
+    }
+    fn from_u64(v: u64) -> Option<Self> {
+        Some(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockDelay, MockInstant, assert_reuse_contract};
+    use fugit::ExtU32;
+
+    fn waiter_100us() -> TickWaiter<MockInstant, NonInterval, u32> {
+        TickWaiter::us(100.micros(), NonInterval::new(), MockInstant::FREQUENCY)
+    }
+
+    #[test]
+    fn cached_sample() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        MockInstant::advance(30);
+        assert!(!t.timeout());
+        assert_eq!(t.elapsed(), Some(30.micros()));
+        assert_eq!(t.remaining(), Some(70.micros()));
+
+        MockInstant::advance(20);
+        assert_eq!(t.elapsed(), Some(30.micros()));
+        assert_eq!(t.remaining(), Some(70.micros()));
+
+        t.refresh();
+        assert_eq!(t.elapsed(), Some(50.micros()));
+        assert_eq!(t.remaining(), Some(50.micros()));
+
+        MockInstant::advance(50);
+        assert!(t.timeout());
+        assert_eq!(t.remaining(), Some(100.micros()));
+        assert_eq!(t.timeout_duration(), Some(100.micros()));
+    }
+
+    #[test]
+    fn is_reusable() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        assert!(t.is_reusable());
+        MockInstant::advance(100);
+        assert_reuse_contract(&mut t);
+
+        let mut t = w.start_oneshot();
+        assert!(!t.is_reusable());
+        MockInstant::advance(100);
+        assert_reuse_contract(&mut t);
+    }
+
+    #[test]
+    fn sub_resolution() {
+        const F: u32 = 1_000;
+        let w = TickWaiter::<MockInstant, _, _>::us(300.micros(), NonInterval::new(), F);
+        assert_eq!(w.resolution(), NanosDurationU32::from_ticks(1_000_000));
+        assert_eq!(w.timeout_tick, 1);
+        let mut t = w.start();
+        assert_eq!(t.timeout_duration(), Some(1_000.micros()));
+        for _ in 0..3 {
+            assert!(!t.timeout());
+        }
+        MockInstant::advance(1);
+        assert!(t.timeout());
+
+        let w = TickWaiter::<MockInstant, _, _>::us(0.micros(), NonInterval::new(), F);
+        assert!(w.start().timeout());
+
+        let w = TickWaiter::<MockInstant, _, u32>::us(1.micros(), NonInterval::new(), 72_000_000);
+        assert_eq!(w.resolution(), NanosDurationU32::from_ticks(14));
+    }
+
+    #[test]
+    fn fork() {
+        let w = waiter_100us();
+        let mut parent = w.start();
+        MockInstant::advance(60);
+        assert!(!parent.timeout());
+
+        let mut child = parent.fork(80.micros());
+        assert_eq!(child.deadline_ticks(), 40);
+        MockInstant::advance(39);
+        assert!(!child.timeout());
+        assert!(!parent.timeout());
+        MockInstant::advance(1);
+        assert!(child.timeout());
+        assert!(parent.timeout());
+
+        // A shorter child keeps its own timeout.
+        let child = parent.fork(10.micros());
+        assert_eq!(child.deadline_ticks(), 10);
+    }
+
+    #[test]
+    fn sleep_remaining() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        let start = MockInstant::now();
+        for i in 1..=3 {
+            // Do some work.
+            MockInstant::advance(30);
+            t.sleep_remaining(&mut MockDelay);
+            assert!(t.timeout());
+            assert_eq!(MockInstant::now().tick_since(start), i * 100);
+        }
+
+        // Already exhausted.
+        MockInstant::advance(150);
+        t.sleep_remaining(&mut MockDelay);
+        assert_eq!(MockInstant::now().tick_since(start), 450);
+    }
+
+    #[test]
+    fn last_slept() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        assert!(!t.last_slept());
+        assert!(!t.timeout());
+        assert!(t.last_slept());
+        MockInstant::advance(100);
+        assert!(t.timeout());
+        assert!(!t.last_slept());
+        assert!(!t.timeout());
+        assert!(t.last_slept());
+        // `check()` doesn't change it.
+        assert!(!t.check());
+        assert!(t.last_slept());
+        t.restart();
+        assert!(!t.last_slept());
+    }
+
+    #[test]
+    fn timeout_with_grace() {
+        let w = waiter_100us();
+        let mut t = w.start_oneshot();
+        assert!(!t.timeout_with_grace(|| panic!("called before expiry")));
+        MockInstant::advance(100);
+        // The flag is set just in time.
+        assert!(!t.timeout_with_grace(|| true));
+        assert!(t.timeout_with_grace(|| false));
+    }
+
+    #[test]
+    fn discount() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        MockInstant::advance(60);
+        assert!(!t.timeout());
+        t.discount(20);
+        assert_eq!(t.remaining(), Some(60.micros()));
+        MockInstant::advance(59);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+
+        MockInstant::advance(30);
+        t.refresh();
+        t.discount(50);
+        assert_eq!(t.elapsed(), Some(0.micros()));
+        assert_eq!(t.remaining(), Some(100.micros()));
+    }
+
+    #[test]
+    fn oneshot_latched() {
+        let interval = FnInterval::new(|| MockInstant::advance(1));
+        let w = TickWaiter::<MockInstant, _, _>::us(10.micros(), &interval, MockInstant::FREQUENCY);
+        let mut t = w.start_oneshot();
+        MockInstant::advance(10);
+        assert!(t.timeout());
+
+        // Neither the clock nor the interval is touched.
+        let before = MockInstant::now();
+        MockInstant::forbid_reads(true);
+        for _ in 0..3 {
+            assert!(t.timeout());
+        }
+        MockInstant::forbid_reads(false);
+        assert_eq!(t.poll_count(), 4);
+        assert_eq!(MockInstant::now(), before);
+    }
+
+    #[test]
+    fn as_dyn() {
+        fn poll(t: &mut dyn WaiterStatus) -> bool {
+            t.timeout()
+        }
+
+        let w = waiter_100us();
+        let mut t = w.start();
+        assert!(!poll(t.as_dyn()));
+        MockInstant::advance(100);
+        assert!(poll(&mut t));
+        assert_eq!(t.as_dyn().poll_count(), 2);
+    }
+
+    #[test]
+    fn step() {
+        let interval = FnInterval::new(|| panic!("interval called"));
+        let w =
+            TickWaiter::<MockInstant, _, _>::us(100.micros(), &interval, MockInstant::FREQUENCY);
+        let mut t = w.start();
+        let mut steps = 0;
+        while t.step().is_continue() {
+            steps += 1;
+            MockInstant::advance(10);
+        }
+        assert_eq!(steps, 10);
+    }
+
+    #[test]
+    fn saturated_elapsed() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        MockInstant::advance(u32::MAX);
+        t.refresh();
+        MockInstant::advance(u32::MAX);
+        assert!(t.timeout());
+        assert!(!t.timeout());
+        assert_eq!(t.elapsed(), Some(0.micros()));
+        MockInstant::advance(99);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+    }
+
+    #[test]
+    fn collect_until() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        let mut n = 0;
+        let mut events = std::vec::Vec::new();
+        t.collect_until(
+            || {
+                MockInstant::advance(10);
+                n += 1;
+                (n % 3 != 0).then_some(n)
+            },
+            &mut events,
+        );
+        assert_eq!(events, [1, 2, 4, 5, 7, 8, 10]);
+    }
+
+    #[test]
+    fn extend_to() {
+        let w = waiter_100us();
+        let mut t = w.start_oneshot();
+        assert_eq!(t.deadline_ticks(), 100);
+        MockInstant::advance(80);
+        assert!(!t.timeout());
+        t.extend_to(150);
+        MockInstant::advance(60);
+        assert!(!t.timeout());
+        MockInstant::advance(10);
+        assert!(t.timeout());
+
+        t.restart();
+        MockInstant::advance(80);
+        assert!(!t.timeout());
+        // Already passed
+        t.extend_to(50);
+        assert!(t.timeout());
+
+        t.restart();
+        t.extend(20);
+        assert_eq!(t.deadline_ticks(), 70);
+    }
+
+    #[test]
+    fn reset_and_extend() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        MockInstant::advance(99);
+        assert!(!t.timeout());
+        t.reset_and_extend(300.micros());
+        MockInstant::advance(1);
+        assert!(!t.timeout());
+        MockInstant::advance(298);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+        assert_eq!(t.timeout_duration(), Some(300.micros()));
+    }
+
+    #[test]
+    fn saturating_ticks_to_us() {
+        assert_eq!(ticks_to_us(u32::MAX as u64, 1_000_000).ticks(), u32::MAX);
+        assert_eq!(
+            ticks_to_us(u32::MAX as u64 + 1, 1_000_000).ticks(),
+            u32::MAX
+        );
+        assert_eq!(
+            ticks_to_us(u32::MAX as u64, 2_000_000).ticks(),
+            u32::MAX / 2
+        );
+        // It used to overflow in the multiplication.
+        assert_eq!(ticks_to_us(u64::MAX, 1_000_000_000).ticks(), u32::MAX);
+        assert_eq!(ticks_to_us(u64::MAX, 32_768).ticks(), u32::MAX);
+        let max_ticks = u32::MAX as u64 * 32_768 / 1_000_000;
+        assert!(ticks_to_us(max_ticks, 32_768).ticks() > u32::MAX - 31);
+        assert_eq!(ticks_to_us(max_ticks + 1, 32_768).ticks(), u32::MAX);
+
+        let w = TickWaiter::<MockInstant, _, _>::us_u64(
+            MicrosDurationU64::from_ticks(u32::MAX as u64 + 1_000),
+            NonInterval::new(),
+            MockInstant::FREQUENCY,
+        );
+        let t = w.start();
+        assert_eq!(t.timeout_duration().unwrap().ticks(), u32::MAX);
+        assert_eq!(t.remaining().unwrap().ticks(), u32::MAX);
+    }
+
+    #[test]
+    fn render_progress() {
+        use std::string::String;
+
+        let w = waiter_100us();
+        let mut t = w.start_oneshot();
+        let render = |t: &TickWaiterStatus<_, _, _>, width| {
+            let mut s = String::new();
+            t.render_progress(&mut s, width).unwrap();
+            s
+        };
+        assert_eq!(render(&t, 8), "[--------] 0%");
+        MockInstant::advance(50);
+        t.refresh();
+        assert_eq!(t.percent_done(), Some(50));
+        assert_eq!(render(&t, 8), "[####----] 50%");
+        assert_eq!(render(&t, 0), "[] 50%");
+        MockInstant::advance(30);
+        t.refresh();
+        assert_eq!(render(&t, 10), "[########--] 80%");
+        MockInstant::advance(30);
+        assert!(t.timeout());
+        assert_eq!(render(&t, 8), "[########] 100%");
+    }
+
+    #[test]
+    fn expired_at() {
+        let w = waiter_100us();
+        let mut t = w.start();
+        MockInstant::advance(90);
+        assert!(!t.timeout());
+        assert_eq!(t.expired_at(), None);
+        MockInstant::advance(15);
+        assert!(t.timeout());
+        let expired_at = MockInstant::now();
+        assert_eq!(t.expired_at(), Some(expired_at));
+        MockInstant::advance(100);
+        assert!(t.timeout());
+        assert_eq!(t.expired_at(), Some(expired_at));
+
+        t.restart();
+        assert_eq!(t.expired_at(), None);
+    }
+
+    #[test]
+    fn const_fn() {
+        const W: TickWaiter<MockInstant, NonInterval, u32> =
+            TickWaiter::us_const(100, NonInterval::new(), 8 * MockInstant::FREQUENCY);
+        const TIMEOUT_TICK: u32 = W.timeout_tick;
+        assert_eq!(TIMEOUT_TICK, 800);
+        const MS: TickWaiter<MockInstant, NonInterval, u32> =
+            TickWaiter::ms_const(3, NonInterval::new(), 32_000);
+        assert_eq!(MS.timeout_tick, 96);
+        const MTIME: TickWaiter<MockInstant, NonInterval, u32> =
+            TickWaiter::ms_const(3, NonInterval::new(), 32_768);
+        assert_eq!(MTIME.timeout_tick, 99);
+        assert_eq!(
+            MTIME.timeout_tick,
+            TickWaiter::<MockInstant, _, u32>::ms(3.millis(), NonInterval::new(), 32_768)
+                .timeout_tick
+        );
+    }
+
+    #[test]
+    fn oneshot() {
+        let w = waiter_100us();
+        let mut periodic = w.start();
+        let mut oneshot = w.start_oneshot();
+        MockInstant::advance(100);
+        assert!(periodic.timeout());
+        assert!(oneshot.timeout());
+        for _ in 0..10 {
+            MockInstant::advance(5);
+            assert!(!periodic.timeout());
+            assert!(oneshot.timeout());
+        }
+
+        oneshot.restart();
+        assert!(!oneshot.timeout());
+        MockInstant::advance(100);
+        assert!(oneshot.timeout());
+        assert!(oneshot.timeout());
+    }
+
+    #[test]
+    fn overflow_boundary() {
+        const F: u32 = 72_000_000;
+        let max = u32::MAX / 72;
+        let w = TickWaiter::<MockInstant, _, _>::try_us(max.micros(), NonInterval::new(), F);
+        assert_eq!(w.unwrap().timeout_tick, max * 72);
+        let w = TickWaiter::<MockInstant, _, _>::try_us((max + 1).micros(), NonInterval::new(), F);
+        assert_eq!(w.err(), Some(TimeoutError::Overflow));
+
+        let w = TickWaiter::<MockInstant, _, _>::try_ns(u32::MAX.nanos(), NonInterval::new(), F);
+        assert_eq!(
+            w.unwrap().timeout_tick,
+            (u32::MAX as u64 * 72).div_ceil(1_000) as u32
+        );
+
+        let max = u64::MAX / 72;
+        let w = TickWaiter::<MockInstant, _, _>::try_us_u64(
+            MicrosDurationU64::from_ticks(max),
+            NonInterval::new(),
+            F,
+        );
+        assert_eq!(w.unwrap().timeout_tick, max * 72);
+        let w = TickWaiter::<MockInstant, _, _>::try_us_u64(
+            MicrosDurationU64::from_ticks(max + 1),
+            NonInterval::new(),
+            F,
+        );
+        assert_eq!(w.err(), Some(TimeoutError::Overflow));
+        // It used to overflow in the multiplication before dividing.
+        let w = TickWaiter::<MockInstant, _, _>::try_ns_u64(
+            NanosDurationU64::from_ticks(u64::MAX),
+            NonInterval::new(),
+            F,
+        );
+        assert_eq!(
+            w.unwrap().timeout_tick,
+            (u64::MAX as u128 * 72).div_ceil(1_000) as u64
+        );
+
+        let w = TickWaiter::<MockInstant, _, _>::try_us(1.micros(), NonInterval::new(), 1_500_000);
+        assert_eq!(w.unwrap().timeout_tick, 2);
+        let w = TickWaiter::<MockInstant, _, _>::try_us(1.micros(), NonInterval::new(), 0);
+        assert_eq!(w.err(), Some(TimeoutError::InvalidFrequency));
+    }
+
+    #[test]
+    fn mtime_32768hz() {
+        use crate::mock::MockMtime;
+
+        const HOUR: u64 = 3600 * MockMtime::FREQUENCY as u64;
+        MockMtime::set((1 << 40) - HOUR);
+        let w = TickWaiter::<MockMtime, _, u64>::us_u64(
+            MicrosDurationU64::secs(5 * 3600),
+            NonInterval::new(),
+            MockMtime::FREQUENCY,
+        );
+        assert_eq!(w.timeout_tick, 5 * HOUR);
+
+        let mut t = w.start();
+        for _ in 0..5 {
+            assert!(!t.timeout());
+            MockMtime::advance(HOUR - 1);
+        }
+        assert!(!t.timeout());
+        MockMtime::advance(5);
+        assert!(t.timeout());
+
+        // Not a whole number of ticks.
+        let w = TickWaiter::<MockMtime, _, u64>::us_u64(
+            1.micros().into(),
+            NonInterval::new(),
+            MockMtime::FREQUENCY,
+        );
+        assert_eq!(w.timeout_tick, 1);
+    }
+
+    #[test]
+    fn max_timeout() {
+        assert_eq!(super::max_timeout::<u32>(1_000_000).ticks(), 4_294_967_295);
+        assert_eq!(super::max_timeout::<u32>(72_000_000).ticks(), 59_652_323);
+        assert_eq!(
+            super::max_timeout::<u64>(1_000_000_000).ticks(),
+            18_446_744_073_709_551
+        );
+        assert_eq!(super::max_timeout::<u64>(32_768).ticks(), u64::MAX);
+
+        let max = super::max_timeout::<u32>(72_000_000);
+        let w = TickWaiter::<MockInstant, _, u32>::try_us(
+            MicrosDurationU32::from_ticks(max.ticks() as u32),
+            NonInterval::new(),
+            72_000_000,
+        );
+        assert!(w.is_ok());
+        let w = TickWaiter::<MockInstant, _, u32>::try_us(
+            MicrosDurationU32::from_ticks(max.ticks() as u32 + 1),
+            NonInterval::new(),
+            72_000_000,
+        );
+        assert_eq!(w.err(), Some(TimeoutError::Overflow));
+    }
+
+    #[test]
+    fn extend_after_latch() {
+        let w = waiter_100us();
+        let mut t = w.start_oneshot();
+        MockInstant::advance(100);
+        assert!(t.timeout());
+
+        t.extend(50);
+        assert!(t.timeout());
+        t.extend_to(1_000);
+        assert!(t.timeout());
+        t.discount(100);
+        assert!(t.timeout());
+
+        // Still extended after `restart()`.
+        t.restart();
+        MockInstant::advance(999);
+        assert!(!t.timeout());
+        MockInstant::advance(1);
+        assert!(t.timeout());
+    }
 }
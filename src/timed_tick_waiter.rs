@@ -32,6 +32,7 @@ use fugit::MicrosDurationU32;
 /// ```
 pub struct TimedTickWaiter<T, I> {
     frequency: u32,
+    ticks_per_us: u32,
     interval: I,
     _t: PhantomData<T>,
 }
@@ -42,12 +43,29 @@ where
     I: Interval,
 {
     pub fn new(interval: I, frequency: u32) -> Self {
-        assert_eq!(frequency % 1_000_000, 0);
-        Self {
+        Self::try_new(interval, frequency).unwrap()
+    }
+
+    /// The same as [`new()`](Self::new), but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// - [`TimeoutError::InvalidFrequency`]: `frequency` is `0` or isn't a multiple of 1 MHz.
+    pub fn try_new(interval: I, frequency: u32) -> Result<Self, TimeoutError> {
+        if frequency == 0 || !frequency.is_multiple_of(1_000_000) {
+            return Err(TimeoutError::InvalidFrequency);
+        }
+        Ok(Self {
             frequency,
+            ticks_per_us: frequency / 1_000_000,
             interval,
             _t: PhantomData,
-        }
+        })
+    }
+
+    #[inline]
+    fn timeout_tick(&self, timeout: MicrosDurationU32) -> u32 {
+        timeout.ticks().checked_mul(self.ticks_per_us).unwrap()
     }
 }
 
@@ -56,50 +74,87 @@ where
     T: TickInstant,
     I: Interval,
 {
-    fn start(&self, timeout: MicrosDurationU32) -> impl WaiterStatus {
-        TimedTickWaiterStatus::<T, I> {
-            tick: T::now(),
-            timeout_tick: timeout
-                .ticks()
-                .checked_mul(self.frequency / 1_000_000)
-                .unwrap(),
-            elapsed_tick: 0,
-            waiter: self,
-        }
+    #[allow(refining_impl_trait)]
+    fn start(&self, timeout: MicrosDurationU32) -> TimedTickWaiterStatus<'_, T, I> {
+        TickWaiterStatus::new(self.timeout_tick(timeout), self.frequency, &self.interval)
     }
 }
 
-pub struct TimedTickWaiterStatus<'a, T: TickInstant, I: Interval> {
-    tick: T,
-    timeout_tick: u32,
-    elapsed_tick: u32,
-    waiter: &'a TimedTickWaiter<T, I>,
-}
-
-impl<'a, T, I> WaiterStatus for TimedTickWaiterStatus<'a, T, I>
+impl<T, I> TimedTickWaiter<T, I>
 where
     T: TickInstant,
     I: Interval,
 {
-    /// Can be reused without calling `restart()`.
-    #[inline]
-    fn timeout(&mut self) -> bool {
-        let now = T::now();
-        self.elapsed_tick = self.elapsed_tick.add_u32(now.tick_since(self.tick));
-        self.tick = now;
+    /// Set timeout and start waiting in one-shot mode.
+    ///
+    /// Unlike [`start()`](TimedWaiter::start), once `timeout()` returns `true`,
+    /// it keeps returning `true` until `restart()` is called.
+    pub fn start_oneshot(&self, timeout: MicrosDurationU32) -> TimedTickWaiterStatus<'_, T, I> {
+        TickWaiterStatus::new(self.timeout_tick(timeout), self.frequency, &self.interval).oneshot()
+    }
+
+    /// Set timeout and start waiting with `interval` for this wait.
+    ///
+    /// The interval given to [`new()`](Self::new) is ignored for this call,
+    /// so each wait can choose, e.g. spinning for short timeouts and sleeping for long ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use waiter_trait::{prelude::*, NonInterval, StdInterval, TimedTickWaiter};
+    ///
+    /// let w = TimedTickWaiter::<Instant, _>::new(
+    ///     NonInterval::new(),
+    ///     Duration::from_secs(1).as_nanos() as u32,
+    /// );
+    ///
+    /// let mut t = w.start_with(100.micros(), NonInterval::new());
+    /// while !t.timeout() {}
+    ///
+    /// let mut t = w.start_with(50.millis(), StdInterval::new(Duration::from_millis(30)));
+    /// assert!(!t.timeout());
+    /// assert!(!t.timeout());
+    /// assert!(t.timeout());
+    /// ```
+    pub fn start_with<J: Interval>(
+        &self,
+        timeout: MicrosDurationU32,
+        interval: J,
+    ) -> TickWaiterStatus<T, J, u32> {
+        TickWaiterStatus::new(self.timeout_tick(timeout), self.frequency, interval)
+    }
+}
+
+/// [`WaiterStatus`] returned by [`TimedTickWaiter`].
+pub type TimedTickWaiterStatus<'a, T, I> = TickWaiterStatus<T, &'a I, u32>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInstant;
+    use fugit::ExtU32;
 
-        if self.elapsed_tick >= self.timeout_tick {
-            self.elapsed_tick -= self.timeout_tick;
-            true
-        } else {
-            self.waiter.interval.interval();
-            false
+    #[test]
+    fn precomputed_ticks_per_us() {
+        for frequency in [1_000_000, 8_000_000, 72_000_000, 480_000_000] {
+            let w = TimedTickWaiter::<MockInstant, _>::new(NonInterval::new(), frequency);
+            for timeout in [0, 1, 10, 500, 8_000] {
+                assert_eq!(
+                    w.timeout_tick(timeout.micros()),
+                    timeout * (frequency / 1_000_000)
+                );
+            }
         }
     }
 
-    #[inline(always)]
-    fn restart(&mut self) {
-        self.tick = T::now();
-        self.elapsed_tick = 0;
+    #[test]
+    fn try_new() {
+        let w = TimedTickWaiter::<MockInstant, _>::try_new(NonInterval::new(), 8_000_000);
+        assert_eq!(w.unwrap().ticks_per_us, 8);
+        let w = TimedTickWaiter::<MockInstant, _>::try_new(NonInterval::new(), 32_768);
+        assert_eq!(w.err(), Some(TimeoutError::InvalidFrequency));
+        let w = TimedTickWaiter::<MockInstant, _>::try_new(NonInterval::new(), 0);
+        assert_eq!(w.err(), Some(TimeoutError::InvalidFrequency));
     }
 }
@@ -0,0 +1,122 @@
+use core::time::Duration;
+use fugit::MicrosDurationU64;
+
+/// A timeout independent of the frequency and the tick width.
+///
+/// It keeps the duration in microseconds, so the same value can configure
+/// waiters with different clocks, such as [`TickWaiter::with_timeout()`](crate::TickWaiter::with_timeout)
+/// and `StdWaiter::with_timeout()`.
+///
+/// Conversions into it round up to whole microseconds and saturate at `u64::MAX` microseconds
+/// (about 584,000 years):
+/// - From [`core::time::Duration`].
+/// - From any [`fugit::Duration`], e.g. [`MillisDurationU32`](fugit::MillisDurationU32) or
+///   [`NanosDurationU64`](fugit::NanosDurationU64).
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use waiter_trait::{fugit::MillisDurationU32, Timeout};
+///
+/// let a = Timeout::from(Duration::from_millis(1500));
+/// let b = Timeout::from(MillisDurationU32::from_ticks(1500));
+/// assert_eq!(a, b);
+/// assert_eq!(a.micros().ticks(), 1_500_000);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timeout(MicrosDurationU64);
+
+impl Timeout {
+    /// Create it from microseconds.
+    pub const fn new(timeout: MicrosDurationU64) -> Self {
+        Self(timeout)
+    }
+
+    /// The timeout in microseconds.
+    #[inline(always)]
+    pub const fn micros(self) -> MicrosDurationU64 {
+        self.0
+    }
+}
+
+impl From<Duration> for Timeout {
+    fn from(d: Duration) -> Self {
+        let us = d.as_nanos().div_ceil(1_000);
+        Self(MicrosDurationU64::from_ticks(
+            us.min(u64::MAX as u128) as u64
+        ))
+    }
+}
+
+impl From<Timeout> for Duration {
+    fn from(t: Timeout) -> Self {
+        Duration::from_micros(t.0.ticks())
+    }
+}
+
+macro_rules! impl_from_fugit {
+    ($($ty:ty),*) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> From<fugit::Duration<$ty, NOM, DENOM>> for Timeout {
+                fn from(d: fugit::Duration<$ty, NOM, DENOM>) -> Self {
+                    let us = (d.ticks() as u128 * NOM as u128 * 1_000_000).div_ceil(DENOM as u128);
+                    Self(MicrosDurationU64::from_ticks(us.min(u64::MAX as u128) as u64))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_fugit!(u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fugit::{MicrosDurationU32, MillisDurationU32, NanosDurationU32, SecsDurationU64};
+
+    #[test]
+    fn conversions() {
+        let us = |t: Timeout| t.micros().ticks();
+
+        assert_eq!(us(Duration::from_secs(3).into()), 3_000_000);
+        assert_eq!(us(Duration::from_nanos(1_001).into()), 2);
+        assert_eq!(us(Duration::MAX.into()), u64::MAX);
+
+        assert_eq!(us(MicrosDurationU32::from_ticks(250).into()), 250);
+        assert_eq!(us(MillisDurationU32::from_ticks(7).into()), 7_000);
+        assert_eq!(
+            us(MicrosDurationU64::from_ticks(5_000_000_000).into()),
+            5_000_000_000
+        );
+        assert_eq!(
+            us(SecsDurationU64::from_ticks(25 * 3600).into()),
+            90_000_000_000
+        );
+        assert_eq!(us(NanosDurationU32::from_ticks(1).into()), 1);
+        assert_eq!(us(SecsDurationU64::from_ticks(u64::MAX).into()), u64::MAX);
+
+        let t = Timeout::from(Duration::from_micros(42));
+        assert_eq!(Duration::from(t), Duration::from_micros(42));
+    }
+
+    #[test]
+    fn configure_waiters() {
+        use crate::{mock::MockInstant, *};
+
+        let t = Timeout::from(Duration::from_millis(2));
+        let w = TickWaiter::<MockInstant, _, u64>::with_timeout(t, NonInterval::new(), 32_768);
+        assert_eq!(w.timeout_tick, 66);
+        let w = TickWaiter::<MockInstant, _, u32>::try_with_timeout(t, NonInterval::new(), 0);
+        assert_eq!(w.err(), Some(TimeoutError::InvalidFrequency));
+
+        #[cfg(feature = "std")]
+        {
+            let w = StdWaiter::with_timeout(t, NonInterval::new());
+            assert_eq!(
+                w.start().timeout_duration(),
+                Some(fugit::MicrosDurationU32::from_ticks(2_000))
+            );
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use super::*;
+use fugit::{MicrosDurationU32, MicrosDurationU64};
+
+/// [`Waiter`] implementation driven by a function returning a timestamp in microseconds.
+///
+/// It's handy for RTOS or scheduler integrations exposing a global time function,
+/// without implementing [`TickInstant`].
+///
+/// The function must be monotonic: the timestamp never decreases.
+/// It's `u64`, so it won't wrap around in practice.
+///
+/// Like [`TickWaiter`], the status can be reused without calling `restart()`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, NonInterval, TimestampWaiter};
+///
+/// fn now_us() -> u64 {
+///     static START: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+///     START.get_or_init(Instant::now).elapsed().as_micros() as u64
+/// }
+///
+/// let w = TimestampWaiter::new(now_us, 10.millis().into(), NonInterval::new());
+/// let mut t = w.start();
+/// assert!(!t.timeout());
+/// std::thread::sleep(Duration::from_millis(10));
+/// assert!(t.timeout());
+/// ```
+pub struct TimestampWaiter<I> {
+    now: fn() -> u64,
+    timeout: u64,
+    interval: I,
+}
+
+impl<I: Interval> TimestampWaiter<I> {
+    /// - `now`: returns a monotonic timestamp in microseconds.
+    /// - `timeout`
+    /// - `interval`: Before the time limit expires,
+    ///   this action will execute each time `timeout()` is called.
+    pub fn new(now: fn() -> u64, timeout: MicrosDurationU64, interval: I) -> Self {
+        Self {
+            now,
+            timeout: timeout.ticks(),
+            interval,
+        }
+    }
+}
+
+impl<I: Interval> Waiter for TimestampWaiter<I> {
+    #[inline]
+    fn start(&self) -> impl WaiterStatus {
+        let now = (self.now)();
+        TimestampWaiterStatus {
+            start: now,
+            now,
+            poll_count: 0,
+            slept: false,
+            waiter: self,
+        }
+    }
+}
+
+/// [`WaiterStatus`] returned by [`TimestampWaiter`].
+pub struct TimestampWaiterStatus<'a, I> {
+    start: u64,
+    now: u64,
+    poll_count: u32,
+    slept: bool,
+    waiter: &'a TimestampWaiter<I>,
+}
+
+impl<'a, I> TimestampWaiterStatus<'a, I> {
+    #[inline(always)]
+    fn elapsed_us(&self) -> u64 {
+        self.now.saturating_sub(self.start)
+    }
+}
+
+impl<'a, I: Interval> WaiterStatus for TimestampWaiterStatus<'a, I> {
+    /// Can be reused without calling `restart()`.
+    #[inline]
+    fn timeout(&mut self) -> bool {
+        let expired = self.check();
+        interval_unless(expired, &self.waiter.interval, &mut self.slept)
+    }
+
+    #[inline]
+    fn check(&mut self) -> bool {
+        self.refresh();
+        self.poll_count = self.poll_count.saturating_add(1);
+        if self.elapsed_us() >= self.waiter.timeout {
+            self.start = self.start.saturating_add(self.waiter.timeout);
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn restart(&mut self) {
+        self.start = (self.waiter.now)();
+        self.now = self.start;
+        self.poll_count = 0;
+        self.slept = false;
+    }
+
+    #[inline(always)]
+    fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    #[inline(always)]
+    fn last_slept(&self) -> bool {
+        self.slept
+    }
+
+    #[inline(always)]
+    fn is_reusable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn refresh(&mut self) {
+        self.now = (self.waiter.now)();
+    }
+
+    fn elapsed(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.elapsed_us(), 1_000_000))
+    }
+
+    fn remaining(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(
+            self.waiter.timeout.saturating_sub(self.elapsed_us()),
+            1_000_000,
+        ))
+    }
+
+    fn timeout_duration(&self) -> Option<MicrosDurationU32> {
+        Some(ticks_to_us(self.waiter.timeout, 1_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static NOW: Cell<u64> = const { Cell::new(0) };
+    }
+
+    fn now() -> u64 {
+        NOW.with(|n| n.get())
+    }
+
+    fn advance(us: u64) {
+        NOW.with(|n| n.set(n.get() + us));
+    }
+
+    #[test]
+    fn timestamp() {
+        // Far beyond u32
+        advance(1 << 40);
+        let w = TimestampWaiter::new(
+            now,
+            MicrosDurationU64::from_ticks(5_000_000_000),
+            NonInterval::new(),
+        );
+        let mut t = w.start();
+        advance(4_999_999_999);
+        assert!(!t.timeout());
+        assert_eq!(t.remaining().unwrap().ticks(), 1);
+        advance(1);
+        assert!(t.timeout());
+        assert!(!t.timeout());
+
+        t.restart();
+        advance(5_000_000_000);
+        assert!(t.timeout());
+    }
+
+    #[test]
+    fn is_reusable() {
+        let w = TimestampWaiter::new(now, MicrosDurationU64::from_ticks(100), NonInterval::new());
+        let mut t = w.start();
+        assert!(t.is_reusable());
+        advance(100);
+        crate::mock::assert_reuse_contract(&mut t);
+    }
+}
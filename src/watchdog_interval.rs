@@ -0,0 +1,115 @@
+use super::*;
+use core::cell::Cell;
+use fugit::MicrosDurationU32;
+
+/// [`Interval`] implementation that feeds a window watchdog.
+///
+/// A window watchdog resets the system if it's fed too late, and also if it's fed too early.
+/// `feed` is only called if at least `window` has passed since the last feed,
+/// earlier calls to `interval()` are skipped. Creating it counts as the last feed,
+/// so create it right after the watchdog is started or fed.
+///
+/// Feeding in time is still up to you: `interval()` must be called often enough
+/// before the watchdog's upper limit.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use waiter_trait::{prelude::*, WindowedWatchdogInterval};
+///
+/// let i = WindowedWatchdogInterval::<Instant, _>::new(
+///     || { /* Feed the watchdog */ },
+///     10.millis(),
+///     Duration::from_secs(1).as_nanos() as u32,
+/// );
+/// // Skipped, it's too early.
+/// i.interval();
+/// ```
+#[derive(Clone)]
+pub struct WindowedWatchdogInterval<T: TickInstant, F> {
+    feed: F,
+    window_tick: u32,
+    last_feed: Cell<T>,
+}
+
+impl<T, F> WindowedWatchdogInterval<T, F>
+where
+    T: TickInstant,
+    F: Fn() + Clone,
+{
+    /// - `feed`: feed the watchdog.
+    /// - `window`: the minimum time between two feeds.
+    ///   It's rounded up to whole ticks and saturates at `u32::MAX` ticks.
+    /// - `frequency`: the frequency of `T`.
+    pub fn new(feed: F, window: MicrosDurationU32, frequency: u32) -> Self {
+        Self {
+            feed,
+            window_tick: us_to_ticks(window.ticks() as u64, frequency),
+            last_feed: Cell::new(T::now()),
+        }
+    }
+}
+
+impl<T, F> Interval for WindowedWatchdogInterval<T, F>
+where
+    T: TickInstant,
+    F: Fn() + Clone,
+{
+    #[inline]
+    fn interval(&self) {
+        let now = T::now();
+        if now.tick_since(self.last_feed.get()) >= self.window_tick {
+            (self.feed)();
+            self.last_feed.set(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockInstant, MockMtime};
+    use fugit::ExtU32;
+
+    #[test]
+    fn window() {
+        let feeds = Cell::new(0);
+        let i = WindowedWatchdogInterval::<MockInstant, _>::new(
+            || feeds.set(feeds.get() + 1),
+            100.micros(),
+            MockInstant::FREQUENCY,
+        );
+        MockInstant::advance(50);
+        i.interval();
+        assert_eq!(feeds.get(), 0);
+        MockInstant::advance(50);
+        i.interval();
+        assert_eq!(feeds.get(), 1);
+        MockInstant::advance(99);
+        i.interval();
+        assert_eq!(feeds.get(), 1);
+        MockInstant::advance(1);
+        i.interval();
+        i.interval();
+        assert_eq!(feeds.get(), 2);
+    }
+
+    #[test]
+    fn non_mhz_frequency() {
+        let feeds = Cell::new(0);
+        MockMtime::set(0);
+        // 100 us is 3.3 ticks, rounded up to 4.
+        let i = WindowedWatchdogInterval::<MockMtime, _>::new(
+            || feeds.set(feeds.get() + 1),
+            100.micros(),
+            MockMtime::FREQUENCY,
+        );
+        MockMtime::set(3);
+        i.interval();
+        assert_eq!(feeds.get(), 0);
+        MockMtime::set(4);
+        i.interval();
+        assert_eq!(feeds.get(), 1);
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+static SOURCE: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// [`TickInstant`] implementation for a free-running `u32` counter, such as a timer register.
+///
+/// [`TickInstant::now()`] has no `self`, so a bare `u32` can't know where to read "now" from.
+/// Instead, register the function reading the counter once with
+/// [`set_source()`](Self::set_source), then use `WrappingTick` as the tick type.
+/// The difference is computed with `wrapping_sub`, so the counter may wrap around.
+///
+/// There is only one source for the whole program.
+/// If you need several counters, implement [`TickInstant`] for your own types.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use waiter_trait::{prelude::*, NonInterval, TickWaiter, WrappingTick};
+///
+/// static COUNTER: AtomicU32 = AtomicU32::new(0);
+///
+/// WrappingTick::set_source(|| COUNTER.fetch_add(1, Ordering::Relaxed));
+///
+/// let w = TickWaiter::<WrappingTick, _, _>::us(10.micros(), NonInterval::new(), 1_000_000);
+/// let mut t = w.start();
+/// let mut n = 0;
+/// while !t.timeout() {
+///     n += 1;
+/// }
+/// assert_eq!(n, 9);
+/// ```
+#[derive(Clone, Copy)]
+pub struct WrappingTick(pub u32);
+
+impl WrappingTick {
+    /// Register the function reading the counter.
+    /// It must be called before [`TickInstant::now()`].
+    pub fn set_source(read: fn() -> u32) {
+        SOURCE.store(read as *mut (), Ordering::Release);
+    }
+}
+
+impl TickInstant for WrappingTick {
+    /// # Panics
+    ///
+    /// If no source is registered.
+    #[inline]
+    fn now() -> Self {
+        let read = SOURCE.load(Ordering::Acquire);
+        assert!(!read.is_null(), "WrappingTick source isn't set");
+        // SAFETY: only `set_source()` stores it, from a `fn() -> u32`.
+        let read: fn() -> u32 = unsafe { core::mem::transmute(read) };
+        Self(read())
+    }
+
+    #[inline(always)]
+    fn tick_since(self, earlier: Self) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(WrappingTick(5).tick_since(WrappingTick(u32::MAX - 4)), 10);
+        assert_eq!(WrappingTick(10).tick_since(WrappingTick(3)), 7);
+    }
+}